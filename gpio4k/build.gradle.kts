@@ -72,6 +72,13 @@ kotlin {
             dependsOn(rpiCommonMain)
         }
 
+        val rpiJvmTest by getting {
+            dependencies {
+                implementation(kotlin("test"))
+                implementation(kotlin("test-junit"))
+            }
+        }
+
         val desktopCommonMain by creating {
             dependsOn(commonMain)
         }
@@ -0,0 +1,40 @@
+package dev.thechilli.gpio4k.buzzer
+
+/**
+ * Multiple notes meant to sound at the same time.
+ *
+ * A buzzer can only play one frequency at once, so a chord is approximated by arpeggiation:
+ * cycling rapidly through its notes for the whole duration. Short steps blend better into a chord,
+ * but make each note too short to have a clear pitch; long steps sound like a trill instead.
+ */
+data class Chord(
+    val frequenciesHz: List<UInt>,
+    val durationMs: UInt,
+) {
+    init {
+        require(frequenciesHz.isNotEmpty()) { "Chord must have at least one note" }
+    }
+
+    /**
+     * Splits the chord into single notes of [stepMs] milliseconds each, cycling through its frequencies.
+     * The last note is shortened to fit the chord duration.
+     */
+    fun toArpeggio(stepMs: UInt = DEFAULT_STEP_MS): List<Note> {
+        require(stepMs > 0u) { "Step must be positive" }
+
+        val notes = mutableListOf<Note>()
+        var elapsedMs = 0u
+        var i = 0
+        while (elapsedMs < durationMs) {
+            val noteMs = minOf(stepMs, durationMs - elapsedMs)
+            notes.add(Note(frequenciesHz[i % frequenciesHz.size], noteMs))
+            elapsedMs += noteMs
+            i++
+        }
+        return notes
+    }
+
+    companion object {
+        const val DEFAULT_STEP_MS = 30u
+    }
+}
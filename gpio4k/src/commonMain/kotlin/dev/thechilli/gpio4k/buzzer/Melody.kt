@@ -1,16 +1,198 @@
 package dev.thechilli.gpio4k.buzzer
 
+import dev.thechilli.gpio4k.utils.readTextFile
+import dev.thechilli.gpio4k.utils.writeTextFile
+import kotlin.math.roundToLong
+
 class Melody {
     private val _notes = mutableListOf<Note>()
     val notes: List<Note> = _notes
 
+    /**
+     * Total duration of the melody in milliseconds.
+     */
+    val durationMs: UInt
+        get() = notes.fold(0u) { acc, note -> acc + note.durationMs }
+
     fun add(note: Note) {
         _notes.add(note)
     }
 
+    /**
+     * Adds a chord, played as an arpeggio with steps of [stepMs] milliseconds.
+     *
+     * @see Chord.toArpeggio
+     */
+    fun add(chord: Chord, stepMs: UInt = Chord.DEFAULT_STEP_MS) {
+        _notes.addAll(chord.toArpeggio(stepMs))
+    }
+
+    /**
+     * Adds a note of the given pitch, lasting [durationMs] milliseconds.
+     */
+    fun addNote(note: MusicalNote, durationMs: UInt) {
+        add(note.toNote(durationMs))
+    }
+
+    /**
+     * Adds silence lasting [durationMs] milliseconds.
+     */
+    fun addRest(durationMs: UInt) {
+        add(Note(0u, durationMs))
+    }
+
+    /**
+     * Adds all notes of the [other] melody after the current ones.
+     */
+    fun append(other: Melody) {
+        _notes.addAll(other.notes)
+    }
+
+    /**
+     * Creates a copy of this melody with all durations multiplied by [factor], rounded to whole milliseconds.
+     * A factor above 1 slows the melody down, below 1 speeds it up.
+     */
+    fun scale(factor: Double): Melody {
+        require(factor > 0) { "Scale factor must be positive" }
+        return Melody().also { scaled ->
+            notes.forEach { scaled.add(it.copy(durationMs = (it.durationMs.toDouble() * factor).roundToLong().toUInt())) }
+        }
+    }
+
+    /**
+     * Finds the notes pitched outside of the [min]..[max] range, e.g. ones a buzzer can't reproduce well.
+     * Rests are never reported.
+     *
+     * @return the index and the note of each note out of range, in melody order.
+     */
+    fun validate(min: MusicalNote, max: MusicalNote): List<Pair<Int, Note>> {
+        require(min <= max) { "Minimum note $min is above maximum note $max" }
+
+        val range = min.frequencyHz..max.frequencyHz
+        return notes.withIndex()
+            .filter { (_, note) -> note.frequencyHz != 0u && note.frequencyHz !in range }
+            .map { (index, note) -> index to note }
+    }
+
+    /**
+     * Writes the melody in the text format read by [parseText], one `note,duration_ms` line per note.
+     */
+    fun toText(): String = notes.joinToString("") { note ->
+        val pitch = when (note.frequencyHz) {
+            0u -> "-"
+            else -> MusicalNote.entries.firstOrNull { it.frequencyHz == note.frequencyHz }
+                ?.name?.replace('s', '#')
+                ?: note.frequencyHz.toString()
+        }
+        "$pitch,${note.durationMs}\n"
+    }
+
+    /**
+     * Saves the melody to a file in the text format read by [loadFrom].
+     *
+     * @throws IllegalStateException if the file can't be written.
+     */
+    fun saveTo(path: String) {
+        writeTextFile(path, toText())
+    }
+
     companion object {
         fun of(vararg notes: Note) = Melody().apply {
             notes.forEach { add(it) }
         }
+
+        private val simpleTokenRegex = Regex("([^/]+)/(\\d+)(\\.?)")
+
+        /**
+         * Parses a melody in a simple notation of whitespace-separated `note/denominator` tokens, like
+         * `c4/4 e4/8. g#4/2 r/4`, at the given tempo in quarter notes per minute.
+         *
+         * - The note is a name accepted by [MusicalNote.parse], or `r` for a rest.
+         * - The denominator is the note value: `1` is a whole note, `4` a quarter note, `8` an eighth note.
+         * - A trailing `.` makes the note dotted, lasting one and a half times as long.
+         *
+         * @throws MelodyParseException on an invalid token.
+         */
+        fun parseSimple(text: String, bpm: Int): Melody {
+            require(bpm > 0) { "Tempo must be positive" }
+
+            val wholeNoteMs = 4 * 60_000.0 / bpm
+            val melody = Melody()
+            for (token in text.trim().split(Regex("\\s+")).filter { it.isNotEmpty() }) {
+                val match = simpleTokenRegex.matchEntire(token)
+                    ?: throw MelodyParseException("Invalid token: $token")
+                val (name, denominatorText, dot) = match.destructured
+
+                val denominator = denominatorText.toIntOrNull()?.takeIf { it > 0 }
+                    ?: throw MelodyParseException("Invalid note value in token: $token")
+                var durationMs = wholeNoteMs / denominator
+                if (dot.isNotEmpty()) durationMs *= 1.5
+
+                if (name.equals("r", ignoreCase = true)) {
+                    melody.addRest(durationMs.roundToLong().toUInt())
+                } else {
+                    val note = try {
+                        MusicalNote.parse(name)
+                    } catch (e: IllegalArgumentException) {
+                        throw MelodyParseException("Invalid note in token: $token", e)
+                    }
+                    melody.addNote(note, durationMs.roundToLong().toUInt())
+                }
+            }
+            return melody
+        }
+
+        /**
+         * Parses a melody from a line-based text format, e.g. for user-editable jingles:
+         *
+         * ```
+         * # Unlock jingle
+         * C5,100
+         * -,50
+         * G#5,200
+         * ```
+         *
+         * - Each line is `note,duration_ms`. The note is a name accepted by [MusicalNote.parse], `-` for a rest,
+         *   or a frequency in Hz, which is how [toText] writes frequencies without a matching note.
+         * - Blank lines and lines starting with `#` are ignored.
+         *
+         * @throws MelodyParseException on an invalid line, with its number in [MelodyParseException.line].
+         */
+        fun parseText(text: String): Melody {
+            val melody = Melody()
+            for ((index, rawLine) in text.lines().withIndex()) {
+                val lineNumber = index + 1
+                val line = rawLine.trim()
+                if (line.isEmpty() || line.startsWith("#")) continue
+
+                fun fail(message: String, cause: Throwable? = null): Nothing =
+                    throw MelodyParseException("Line $lineNumber: $message", cause, lineNumber)
+
+                val parts = line.split(",").map { it.trim() }
+                if (parts.size != 2) fail("Expected note,duration_ms but got \"$line\"")
+                val (pitch, durationText) = parts
+
+                val durationMs = durationText.toUIntOrNull() ?: fail("Invalid duration: $durationText")
+                val frequencyHz = when {
+                    pitch == "-" -> 0u
+                    pitch.all { it.isDigit() } -> pitch.toUIntOrNull() ?: fail("Invalid frequency: $pitch")
+                    else -> try {
+                        MusicalNote.parse(pitch).frequencyHz
+                    } catch (e: IllegalArgumentException) {
+                        fail("Invalid note: $pitch", e)
+                    }
+                }
+                melody.add(Note(frequencyHz, durationMs))
+            }
+            return melody
+        }
+
+        /**
+         * Loads a melody from a file in the text format described in [parseText], e.g. a custom jingle.
+         *
+         * @throws IllegalStateException if the file can't be read.
+         * @throws MelodyParseException on an invalid line, with its number in [MelodyParseException.line].
+         */
+        fun loadFrom(path: String): Melody = parseText(readTextFile(path))
     }
 }
@@ -0,0 +1,10 @@
+package dev.thechilli.gpio4k.buzzer
+
+/**
+ * @param line The 1-based line the error is on, for line-based formats.
+ */
+class MelodyParseException(
+    message: String,
+    throwable: Throwable? = null,
+    val line: Int? = null,
+) : Exception(message, throwable)
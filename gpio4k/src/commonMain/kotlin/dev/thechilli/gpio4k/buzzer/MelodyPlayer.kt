@@ -0,0 +1,143 @@
+package dev.thechilli.gpio4k.buzzer
+
+import dev.thechilli.gpio4k.pwm.PwmPin
+import dev.thechilli.gpio4k.pwm.setFrequency
+import dev.thechilli.gpio4k.utils.Event
+
+/**
+ * Plays a [melody] on a PWM [pin] without blocking, unlike [PwmBuzzer], so the display can be updated in sync.
+ *
+ * The pin is only updated when [update] is called, so the timing is as precise as the calls to it.
+ */
+class MelodyPlayer(
+    val pin: PwmPin,
+    val melody: Melody,
+) {
+    /**
+     * Invoked when a new note starts, including rests (with a frequency of 0), and with `null` once the melody ends.
+     * Notes skipped entirely between two [update] calls are not reported.
+     */
+    val onNote: Event<Note?> = Event()
+
+    var elapsedMs: Long = 0
+        private set
+
+    /**
+     * Length of the duty cycle ramp at the start and end of each note, in milliseconds, or `0` for no fading.
+     */
+    var fadeMs: Long = 0
+        private set
+
+    /**
+     * Ramps the duty cycle up from 0 over [fadeMs] milliseconds at the start of each note, and back down at its end,
+     * to avoid the click of the buzzer starting and stopping at full duty.
+     *
+     * While fading, every [update] writes the duty cycle, which on sysfs is a file write each time,
+     * and the ramp is only as smooth as the calls to [update] are frequent, so it should run every millisecond or so.
+     */
+    fun setFadeMs(fadeMs: Long) {
+        require(fadeMs >= 0) { "Fade time must not be negative" }
+        this.fadeMs = fadeMs
+    }
+
+    var isPlaying: Boolean = false
+        private set
+
+    /**
+     * Index of the note currently playing, or `null` if not playing.
+     */
+    var currentIndex: Int? = null
+        private set
+
+    /**
+     * How far the playback is, between 0.0 and 1.0.
+     */
+    val progress: Double
+        get() {
+            val durationMs = melody.durationMs.toLong()
+            if (durationMs == 0L) return 1.0
+            return (elapsedMs.toDouble() / durationMs).coerceAtMost(1.0)
+        }
+
+    /**
+     * Starts playing from the beginning.
+     */
+    fun start() {
+        elapsedMs = 0
+        isPlaying = true
+        currentIndex = null
+        update(0)
+    }
+
+    /**
+     * Advances the playback by [elapsedMs] milliseconds, switching notes as needed.
+     *
+     * @return `true` if the melody is still playing.
+     */
+    fun update(elapsedMs: Long): Boolean {
+        require(elapsedMs >= 0) { "Elapsed time must not be negative" }
+        if (!isPlaying) return false
+
+        this.elapsedMs += elapsedMs
+        val index = noteIndexAt(this.elapsedMs)
+        if (index == null) {
+            stop()
+            onNote.invoke(null)
+            return false
+        }
+
+        val note = melody.notes[index]
+        val ratio = DUTY_RATIO * envelope(index)
+        if (index != currentIndex) {
+            currentIndex = index
+            if (note.frequencyHz == 0u) {
+                pin.disable()
+            } else {
+                pin.setFrequency(note.frequencyHz.toDouble(), ratio)
+                pin.enable()
+            }
+            onNote.invoke(note)
+        } else if (fadeMs > 0 && note.frequencyHz != 0u) {
+            pin.setRatio(ratio)
+        }
+        return true
+    }
+
+    /**
+     * The volume envelope of the note at [index] at the current time, between 0.0 and 1.0.
+     */
+    private fun envelope(index: Int): Double {
+        if (fadeMs == 0L) return 1.0
+
+        val startMs = noteStartMs(index)
+        val endMs = startMs + melody.notes[index].durationMs.toLong()
+        val sinceStart = elapsedMs - startMs
+        val untilEnd = endMs - elapsedMs
+        return minOf(1.0, sinceStart.toDouble() / fadeMs, untilEnd.toDouble() / fadeMs).coerceAtLeast(0.0)
+    }
+
+    private fun noteStartMs(index: Int): Long =
+        melody.notes.take(index).sumOf { it.durationMs.toLong() }
+
+    /**
+     * Stops the playback and disables the pin.
+     */
+    fun stop() {
+        isPlaying = false
+        currentIndex = null
+        pin.disable()
+    }
+
+    private fun noteIndexAt(timeMs: Long): Int? {
+        var endMs = 0L
+        for ((index, note) in melody.notes.withIndex()) {
+            endMs += note.durationMs.toLong()
+            if (timeMs < endMs) return index
+        }
+        return null
+    }
+
+    private companion object {
+        const val DUTY_RATIO = 0.5
+    }
+}
@@ -0,0 +1,126 @@
+package dev.thechilli.gpio4k.buzzer
+
+import dev.thechilli.gpio4k.pwm.PwmPin
+import dev.thechilli.gpio4k.pwm.playNote
+import dev.thechilli.gpio4k.pwm.stopNote
+import dev.thechilli.gpio4k.utils.Event
+
+/**
+ * A click track on a PWM [pin], playing a short click on every beat, with a higher pitched click on the first beat
+ * of each bar.
+ *
+ * Like [MelodyPlayer], it doesn't block: the caller advances it with [update], so the beats are only as precise as
+ * the calls to it.
+ */
+class Metronome(
+    val pin: PwmPin,
+    bpm: Int,
+) {
+    /**
+     * Invoked on every beat with its index within the bar, `0` being the accented downbeat.
+     */
+    val onBeat: Event<Int> = Event()
+
+    var bpm: Int = bpm
+        private set
+
+    /**
+     * The number of beats in a bar. With `1`, every beat is accented.
+     */
+    var beatsPerBar: Int = 4
+        private set
+
+    /**
+     * The length of each click, in milliseconds. Always shorter than [beatIntervalMs], so the clicks stay apart.
+     */
+    var clickMs: Long = 30
+        private set
+
+    var isRunning: Boolean = false
+        private set
+
+    /**
+     * Index of the last beat within the bar.
+     */
+    var beat: Int = 0
+        private set
+
+    private var sinceBeatMs: Long = 0
+
+    /**
+     * Time between two beats in milliseconds, rounded down to a whole millisecond.
+     */
+    val beatIntervalMs: Long
+        get() = 60_000L / bpm
+
+    init {
+        setBpm(bpm)
+    }
+
+    /**
+     * Changes the tempo. When running, the next beat comes one new interval after the last one.
+     */
+    fun setBpm(bpm: Int) {
+        require(bpm > 0) { "BPM must be positive" }
+        require(60_000L / bpm > clickMs) { "Beats at $bpm BPM must be longer than the $clickMs ms click" }
+        this.bpm = bpm
+    }
+
+    fun setBeatsPerBar(beatsPerBar: Int) {
+        require(beatsPerBar > 0) { "Beats per bar must be positive" }
+        this.beatsPerBar = beatsPerBar
+    }
+
+    fun setClickMs(clickMs: Long) {
+        require(clickMs > 0) { "Click length must be positive" }
+        require(clickMs < beatIntervalMs) { "Click must be shorter than the $beatIntervalMs ms beat" }
+        this.clickMs = clickMs
+    }
+
+    /**
+     * Starts from a downbeat, clicking immediately.
+     */
+    fun start() {
+        isRunning = true
+        sinceBeatMs = 0
+        beat = 0
+        click()
+    }
+
+    /**
+     * Advances the metronome by [elapsedMs] milliseconds, clicking on every beat passed and ending the clicks.
+     *
+     * @return `true` if the metronome is running.
+     */
+    fun update(elapsedMs: Long): Boolean {
+        require(elapsedMs >= 0) { "Elapsed time must not be negative" }
+        if (!isRunning) return false
+
+        sinceBeatMs += elapsedMs
+        while (sinceBeatMs >= beatIntervalMs) {
+            sinceBeatMs -= beatIntervalMs
+            beat = (beat + 1) % beatsPerBar
+            click()
+        }
+        if (sinceBeatMs >= clickMs && pin.enabled) pin.stopNote()
+        return true
+    }
+
+    /**
+     * Stops the metronome and silences the pin.
+     */
+    fun stop() {
+        isRunning = false
+        pin.stopNote()
+    }
+
+    private fun click() {
+        pin.playNote(if (beat == 0) ACCENT_NOTE else CLICK_NOTE)
+        onBeat.invoke(beat)
+    }
+
+    private companion object {
+        val CLICK_NOTE = MusicalNote.A5
+        val ACCENT_NOTE = MusicalNote.A6
+    }
+}
@@ -0,0 +1,72 @@
+package dev.thechilli.gpio4k.buzzer
+
+import kotlin.math.pow
+import kotlin.math.roundToInt
+
+/**
+ * A note of the twelve-tone equal temperament scale, from C0 to B8, tuned to A4 = 440 Hz.
+ *
+ * Notes are ordered by pitch, so they can be compared and stepped through with [next] and [prev].
+ */
+enum class MusicalNote {
+    C0, Cs0, D0, Ds0, E0, F0, Fs0, G0, Gs0, A0, As0, B0,
+    C1, Cs1, D1, Ds1, E1, F1, Fs1, G1, Gs1, A1, As1, B1,
+    C2, Cs2, D2, Ds2, E2, F2, Fs2, G2, Gs2, A2, As2, B2,
+    C3, Cs3, D3, Ds3, E3, F3, Fs3, G3, Gs3, A3, As3, B3,
+    C4, Cs4, D4, Ds4, E4, F4, Fs4, G4, Gs4, A4, As4, B4,
+    C5, Cs5, D5, Ds5, E5, F5, Fs5, G5, Gs5, A5, As5, B5,
+    C6, Cs6, D6, Ds6, E6, F6, Fs6, G6, Gs6, A6, As6, B6,
+    C7, Cs7, D7, Ds7, E7, F7, Fs7, G7, Gs7, A7, As7, B7,
+    C8, Cs8, D8, Ds8, E8, F8, Fs8, G8, Gs8, A8, As8, B8;
+
+    /**
+     * Number of semitones from C0.
+     */
+    val semitone: Int
+        get() = ordinal
+
+    val octave: Int
+        get() = ordinal / 12
+
+    /**
+     * The frequency of the note, rounded to the nearest Hz.
+     */
+    val frequencyHz: UInt
+        get() = (440.0 * 2.0.pow((semitone - A4_SEMITONE) / 12.0)).roundToInt().toUInt()
+
+    /**
+     * The note one semitone higher, or `null` for the highest note.
+     */
+    fun next(): MusicalNote? = entries.getOrNull(ordinal + 1)
+
+    /**
+     * The note one semitone lower, or `null` for the lowest note.
+     */
+    fun prev(): MusicalNote? = entries.getOrNull(ordinal - 1)
+
+    /**
+     * Creates a [Note] of this pitch, lasting [durationMs] milliseconds.
+     */
+    fun toNote(durationMs: UInt): Note = Note(frequencyHz, durationMs)
+
+    companion object {
+        private const val A4_SEMITONE = 57
+
+        private val nameRegex = Regex("([A-G])(#?)([0-8])")
+
+        /**
+         * Parses a note name like `C4`, `c#4` or `Cs4`, case-insensitively.
+         *
+         * @throws IllegalArgumentException if the name is not a valid note.
+         */
+        fun parse(name: String): MusicalNote {
+            val normalized = name.uppercase().replace('S', '#')
+            val match = nameRegex.matchEntire(normalized)
+                ?: throw IllegalArgumentException("Invalid note name: $name")
+            val (letter, sharp, octave) = match.destructured
+            val entryName = letter + (if (sharp.isNotEmpty()) "s" else "") + octave
+            return entries.find { it.name == entryName }
+                ?: throw IllegalArgumentException("Invalid note name: $name")
+        }
+    }
+}
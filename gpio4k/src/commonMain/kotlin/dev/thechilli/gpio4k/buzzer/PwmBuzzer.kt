@@ -1,6 +1,7 @@
 package dev.thechilli.gpio4k.buzzer
 
 import dev.thechilli.gpio4k.pwm.PwmPin
+import dev.thechilli.gpio4k.pwm.setFrequency
 import dev.thechilli.gpio4k.utils.sleepMs
 
 class PwmBuzzer(
@@ -13,10 +14,7 @@ class PwmBuzzer(
         }
 
         println("Beeping at $frequencyHz Hz for $durationMs ms")
-        val periodNs = 1_000_000L / frequencyHz.toLong()
-
-        pwmPin.setPeriodNs(periodNs)
-        pwmPin.setRatio(0.5)
+        pwmPin.setFrequency(frequencyHz.toDouble())
 
         pwmPin.enable()
         sleepMs(durationMs.toInt())
@@ -0,0 +1,44 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.pwm.PwmPin
+
+/**
+ * Puts outputs back into a safe state when closed, e.g. so that a crash doesn't leave a lock solenoid energized
+ * or a buzzer sounding.
+ *
+ * On [close], every PWM pin is disabled and every GPIO pin is reset to input, without closing any of them.
+ * Register it first in a [closingScope][dev.thechilli.gpio4k.utils.closingScope] (or wrap the main loop in
+ * [use][dev.thechilli.gpio4k.utils.use]), so it runs when an exception escapes.
+ *
+ * Cleanup is best effort: a failing pin doesn't stop the others from being reset, and the failures are rethrown
+ * together afterward. It can't run if the process is killed or aborts without unwinding.
+ */
+class CleanupGuard(
+    val pins: List<GpioPin>,
+    val pwmPins: List<PwmPin> = emptyList(),
+) : AutoCloseable {
+    override fun close() {
+        val exceptions = mutableListOf<Throwable>()
+
+        for (pwmPin in pwmPins) {
+            try {
+                pwmPin.disable()
+            } catch (e: Throwable) {
+                exceptions.add(e)
+            }
+        }
+        for (pin in pins) {
+            try {
+                pin.reset(GpioIOMode.INPUT)
+            } catch (e: Throwable) {
+                exceptions.add(e)
+            }
+        }
+
+        if (exceptions.isNotEmpty()) {
+            val exception = exceptions.removeAt(0)
+            exceptions.forEach { exception.addSuppressed(it) }
+            throw exception
+        }
+    }
+}
@@ -0,0 +1,65 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * Debounces an input [pin] in software.
+ *
+ * A new level is only accepted once the pin has read it continuously for at least [debounceMs] milliseconds.
+ * The input doesn't measure time on its own, the caller passes the current time to [poll].
+ */
+class DebouncedInput(
+    val pin: GpioPin,
+    val debounceMs: Long,
+) {
+    init {
+        require(debounceMs >= 0) { "Debounce time must not be negative" }
+    }
+
+    /**
+     * The last debounced level of the pin.
+     */
+    var value: Boolean = pin.read()
+        private set
+
+    private var changedSinceMs: Long? = null
+
+    /**
+     * Reads the pin and updates the debounced [value].
+     *
+     * @param nowMs The current time in milliseconds, from any monotonic clock.
+     * @return the debounced level.
+     */
+    fun poll(nowMs: Long): Boolean {
+        if (pin.read() == value) {
+            changedSinceMs = null
+            return value
+        }
+
+        val since = changedSinceMs ?: nowMs.also { changedSinceMs = it }
+        if (nowMs - since >= debounceMs) {
+            value = !value
+            changedSinceMs = null
+        }
+        return value
+    }
+
+    /**
+     * A debounced level change, to the level [high], detected at [atMs].
+     */
+    data class Edge(val high: Boolean, val atMs: Long)
+
+    /**
+     * An endless sequence of debounced level changes, e.g. for logging door events.
+     *
+     * The pin is polled lazily: each `next()` call keeps polling, as fast as possible, until the level changes.
+     * It blocks until then, so take only as many edges as needed, or use [poll] directly in a main loop.
+     *
+     * @param nowMs Returns the current time in milliseconds, from any monotonic clock.
+     */
+    fun edges(nowMs: () -> Long): Sequence<Edge> = sequence {
+        while (true) {
+            val previous = value
+            val now = nowMs()
+            if (poll(now) != previous) yield(Edge(value, now))
+        }
+    }
+}
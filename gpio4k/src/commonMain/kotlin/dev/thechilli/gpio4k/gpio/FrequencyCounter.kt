@@ -0,0 +1,37 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.time.TimeSource
+
+/**
+ * Measures the frequency of a signal on an input [pin] by counting its rising edges.
+ *
+ * Edges are logical, so with [GpioPin.activeLow] set, a rising edge is the transition to the active (low) level.
+ *
+ * The pin is polled as fast as possible, so the maximum measurable frequency is half of the polling rate.
+ * It depends heavily on the backend: a `SysFsGpioPin` read is a file read, which limits it to a few kHz,
+ * while a `GpiodPin` read spawns a process and is only usable for signals of a few Hz.
+ */
+class FrequencyCounter(
+    val pin: GpioPin,
+) {
+    /**
+     * Counts the rising edges over a window of [windowMs] milliseconds.
+     *
+     * @return the measured frequency in Hz.
+     */
+    fun measure(windowMs: Int): Double {
+        require(windowMs > 0) { "Measurement window must be positive" }
+
+        val start = TimeSource.Monotonic.markNow()
+        var last = pin.read()
+        var edges = 0
+
+        while (start.elapsedNow().inWholeMilliseconds < windowMs) {
+            val current = pin.read()
+            if (current && !last) edges++
+            last = current
+        }
+
+        return edges * 1000.0 / windowMs
+    }
+}
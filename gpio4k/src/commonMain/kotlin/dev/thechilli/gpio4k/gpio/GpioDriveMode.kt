@@ -14,5 +14,13 @@ enum class GpioDriveMode {
     /**
      * The output can be driven high, but is high impedance when low.
      */
-    OPEN_SOURCE
+    OPEN_SOURCE,
+    ;
+
+    companion object {
+        /**
+         * The drive mode pins start with and return to on [GpioPin.reset].
+         */
+        val DEFAULT = PUSH_PULL
+    }
 }
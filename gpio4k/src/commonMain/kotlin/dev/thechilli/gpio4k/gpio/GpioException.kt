@@ -1,3 +1,56 @@
 package dev.thechilli.gpio4k.gpio
 
-class GpioException(message: String, throwable: Throwable? = null) : Exception(message, throwable)
+/**
+ * A failure to access or control a GPIO peripheral.
+ *
+ * The nested subclasses mark specific kinds of failures that callers may want to handle separately.
+ * Anything else is thrown as a plain [GpioException].
+ *
+ * @param isRetryable Whether the failure is likely transient, e.g. a line being briefly busy,
+ * so that repeating the operation may succeed.
+ */
+open class GpioException(
+    message: String,
+    throwable: Throwable? = null,
+    val isRetryable: Boolean = false,
+) : Exception(message, throwable) {
+    /**
+     * A value reported by the backend could not be parsed.
+     *
+     * @param what What was being read, e.g. `"period"`.
+     * @param value The raw value that failed to parse.
+     */
+    class ParseFailure(
+        val what: String,
+        val value: String,
+        throwable: Throwable? = null,
+    ) : GpioException("Failed to parse $what: \"$value\"", throwable)
+
+    /**
+     * An operation didn't complete in time, e.g. waiting for a line to change.
+     */
+    class Timeout(
+        message: String,
+        throwable: Throwable? = null,
+    ) : GpioException(message, throwable, isRetryable = true)
+
+    /**
+     * The peripheral can't be used, e.g. because it doesn't exist or is reserved by someone else.
+     */
+    class Unavailable(
+        message: String,
+        throwable: Throwable? = null,
+        isRetryable: Boolean = false,
+    ) : GpioException(message, throwable, isRetryable)
+
+    /**
+     * The backend doesn't support a [feature], e.g. a bias or drive mode.
+     *
+     * @param feature What was requested, e.g. `"Bias PULL_UP"`.
+     * @param backend Which backend refused it, if known.
+     */
+    class Unsupported(
+        val feature: String,
+        val backend: String? = null,
+    ) : GpioException(if (backend != null) "$feature is not supported by $backend" else "$feature is not supported")
+}
@@ -1,11 +1,189 @@
 package dev.thechilli.gpio4k.gpio
 
+import dev.thechilli.gpio4k.results.Result
+import dev.thechilli.gpio4k.utils.bitFromRight
+import dev.thechilli.gpio4k.utils.encodeToUByteArray
 import dev.thechilli.gpio4k.utils.sleepUs
 
-fun Collection<GpioPin>.resetAll(mode: GpioIOMode = GpioIOMode.INPUT) {
+fun Collection<GpioPin>.resetAll(mode: GpioIOMode = GpioIOMode.DEFAULT) {
     forEach { it.reset(mode) }
 }
 
+/**
+ * Sets the bias of the pin and reads it back with [GpioPin.readBias] to make sure it took effect.
+ *
+ * On backends that can't read the bias back, this is a plain [GpioPin.setBias].
+ *
+ * @throws GpioException if the pin reports a different bias afterward.
+ */
+fun GpioPin.setBiasVerified(bias: GpioLineBias): GpioPin {
+    setBias(bias)
+    val actual = readBias() ?: return this
+    if (actual != bias)
+        throw GpioException("Failed to set bias to $bias, the pin reports $actual")
+    return this
+}
+
+/**
+ * Writes each of the [values] to the corresponding pin, stopping at the first failure.
+ */
+fun List<GpioPin>.writeAll(values: List<Boolean>) {
+    require(size == values.size) { "Expected $size values, got ${values.size}" }
+
+    for ((pin, value) in zip(values)) {
+        pin.write(value)
+    }
+}
+
+/**
+ * Writes each of the [values] to the corresponding pin, continuing past failures.
+ *
+ * @return the result of each write, in pin order.
+ */
+fun List<GpioPin>.writeAllBestEffort(values: List<Boolean>): List<Result<Unit, Exception, Nothing>> {
+    require(size == values.size) { "Expected $size values, got ${values.size}" }
+
+    return zip(values).map { (pin, value) ->
+        try {
+            pin.write(value)
+            Result.Success.of(Unit)
+        } catch (e: Exception) {
+            Result.Failure.of(e)
+        }
+    }
+}
+
+/**
+ * Reads all pins into a bitfield, with the first pin as bit 0 (the least significant bit). Supports up to 32 pins.
+ *
+ * @see readBitsMsbFirst
+ */
+fun List<GpioPin>.readBits(): UInt {
+    require(size <= 32) { "Cannot read more than 32 pins into a bitfield" }
+
+    var bits = 0u
+    for ((i, pin) in withIndex()) {
+        if (pin.read()) bits = bits or (1u shl i)
+    }
+    return bits
+}
+
+/**
+ * Writes a bitfield to all pins, with bit 0 (the least significant bit) going to the first pin. Supports up to 32 pins.
+ *
+ * @throws IllegalArgumentException if [bits] doesn't fit in as many bits as there are pins.
+ */
+fun List<GpioPin>.writeBits(bits: UInt) {
+    require(size <= 32) { "Cannot write more than 32 pins from a bitfield" }
+    require(size == 32 || bits < (1u shl size)) { "Value $bits doesn't fit in $size bits" }
+
+    for ((i, pin) in withIndex()) {
+        pin.write((bits shr i) and 1u != 0u)
+    }
+}
+
+/**
+ * Reads all pins into a bitfield, with the first pin as the most significant bit. Supports up to 32 pins.
+ *
+ * This matches pins listed the way they're usually labeled on a bus, e.g. `listOf(d7, d6, d5, d4)` reads a nibble
+ * with `d7` as bit 3, so the list doesn't need reversing. With [readBits], the first pin would be bit 0 instead:
+ *
+ * ```
+ * val rows = listOf(row1, row2, row3, row4)
+ * rows.readBitsMsbFirst() // 0b1000 when only row1 is high
+ * rows.readBits()         // 0b0001 when only row1 is high
+ * ```
+ */
+fun List<GpioPin>.readBitsMsbFirst(): UInt {
+    require(size <= 32) { "Cannot read more than 32 pins into a bitfield" }
+
+    var bits = 0u
+    for (pin in this) {
+        bits = bits shl 1
+        if (pin.read()) bits = bits or 1u
+    }
+    return bits
+}
+
+/**
+ * Writes a bitfield to all pins, with the most significant bit going to the first pin. Supports up to 32 pins.
+ *
+ * See [readBitsMsbFirst] for how this differs from [writeBits].
+ *
+ * @throws IllegalArgumentException if [bits] doesn't fit in as many bits as there are pins.
+ */
+fun List<GpioPin>.writeBitsMsbFirst(bits: UInt) {
+    require(size <= 32) { "Cannot write more than 32 pins from a bitfield" }
+    require(size == 32 || bits < (1u shl size)) { "Value $bits doesn't fit in $size bits" }
+
+    for ((i, pin) in withIndex()) {
+        pin.write((bits shr (size - 1 - i)) and 1u != 0u)
+    }
+}
+
+/**
+ * Reads all pins [samples] times and returns the majority value of each, filtering out single bounces or glitches.
+ *
+ * @param samples Number of reads per pin. Must be odd, so there is never a tie.
+ * @param delayUs Time to wait between consecutive samples.
+ */
+fun List<GpioPin>.readStable(samples: Int = 3, delayUs: Int = 0): List<Boolean> {
+    require(samples > 0 && samples % 2 == 1) { "Sample count must be a positive odd number, got $samples" }
+
+    val highCounts = IntArray(size)
+    repeat(samples) { sample ->
+        if (sample > 0) sleepUs(delayUs)
+        for ((i, pin) in withIndex()) {
+            if (pin.read()) highCounts[i]++
+        }
+    }
+    return highCounts.map { it > samples / 2 }
+}
+
+/**
+ * Switches the pin to output mode for the duration of [block].
+ *
+ * If [restore] is set, the previous mode is restored afterward, even if [block] throws.
+ */
+inline fun <R> GpioPin.withOutput(restore: Boolean = true, block: (GpioPin) -> R): R =
+    withMode(GpioIOMode.OUTPUT, restore, block)
+
+/**
+ * Switches the pin to input mode for the duration of [block].
+ *
+ * If [restore] is set, the previous mode is restored afterward, even if [block] throws.
+ */
+inline fun <R> GpioPin.withInput(restore: Boolean = true, block: (GpioPin) -> R): R =
+    withMode(GpioIOMode.INPUT, restore, block)
+
+@PublishedApi
+internal inline fun <R> GpioPin.withMode(mode: GpioIOMode, restore: Boolean, block: (GpioPin) -> R): R {
+    val previous = this.mode
+    setMode(mode)
+    try {
+        return block(this)
+    } finally {
+        if (restore && previous != mode) setMode(previous)
+    }
+}
+
+/**
+ * Describes the current configuration of the pin for logging, e.g.
+ * `SysFsGpioPin: INPUT, active low, bias NONE, drive PUSH_PULL, reads true`.
+ *
+ * Unlike [toString], this queries the pin, and inputs are read. A failing read is shown as `reads ?`.
+ */
+fun GpioPin.describe(): String = buildString {
+    append(this@describe::class.simpleName)
+    append(": ").append(mode)
+    append(if (activeLow) ", active low" else ", active high")
+    append(", bias ").append(bias)
+    append(", drive ").append(drive)
+    if (mode == GpioIOMode.INPUT) {
+        append(", reads ").append(runCatching { read() }.getOrNull() ?: "?")
+    }
+}
+
 fun GpioPin.keepHigh(delayUs: Int = 10, block: () -> Unit) {
     this.write(true)
     sleepUs(delayUs)
@@ -13,3 +191,48 @@ fun GpioPin.keepHigh(delayUs: Int = 10, block: () -> Unit) {
     this.write(false)
     sleepUs(delayUs)
 }
+
+/**
+ * Writes a byte over a 4-pin bus as two nibbles, the high one first, calling [pulse] after each of them.
+ *
+ * The first pin receives the most significant bit of each nibble.
+ * The [pulse] callback is where the caller latches the nibble, e.g. by toggling an enable pin.
+ */
+fun List<GpioPin>.writeByteAsNibbles(byte: UByte, pulse: () -> Unit) {
+    require(size == 4) { "Nibble bus must have exactly 4 pins" }
+
+    for ((i, pin) in withIndex()) {
+        pin.write(byte.bitFromRight(i))
+    }
+    pulse()
+
+    for ((i, pin) in withIndex()) {
+        pin.write(byte.bitFromRight(i + 4))
+    }
+    pulse()
+}
+
+/**
+ * Writes each of the [bytes] to an 8-pin bus, calling [pulse] after each one.
+ *
+ * Bytes are written in order, and [pulse] runs once every pin holds the current byte, before the next one is written.
+ * The first pin receives the most significant bit. Latch timing is left to [pulse], e.g. toggling a shift register's
+ * storage clock.
+ */
+fun List<GpioPin>.writeBytes(bytes: UByteArray, pulse: () -> Unit) {
+    require(size == 8) { "Byte bus must have exactly 8 pins" }
+
+    for (byte in bytes) {
+        for ((i, pin) in withIndex()) {
+            pin.write(byte.bitFromRight(i))
+        }
+        pulse()
+    }
+}
+
+/**
+ * Writes the UTF-8 bytes of [str] to an 8-pin bus, calling [pulse] after each one. See [writeBytes].
+ */
+fun List<GpioPin>.writeString(str: String, pulse: () -> Unit) {
+    writeBytes(str.encodeToUByteArray(), pulse)
+}
@@ -3,4 +3,12 @@ package dev.thechilli.gpio4k.gpio
 enum class GpioIOMode {
     INPUT,
     OUTPUT,
+    ;
+
+    companion object {
+        /**
+         * The mode pins start in and return to on [GpioPin.reset].
+         */
+        val DEFAULT = INPUT
+    }
 }
@@ -4,4 +4,12 @@ enum class GpioLineBias {
     NONE,
     PULL_UP,
     PULL_DOWN,
+    ;
+
+    companion object {
+        /**
+         * The bias pins start with and return to on [GpioPin.reset].
+         */
+        val DEFAULT = NONE
+    }
 }
@@ -11,6 +11,18 @@ interface GpioPin : AutoCloseable {
      */
     fun read(): Boolean
 
+    /**
+     * Reads the pin once and returns both its electrical level and its logical value, with [activeLow] applied,
+     * e.g. to debug an active-low button with a pull-up, which is electrically high but logically `false` when idle.
+     *
+     * @return the electrical level (`true` when HIGH) and the logical value, in that order.
+     * @throws GpioException if the pin is not readable
+     */
+    fun readBoth(): Pair<Boolean, Boolean> {
+        val logical = read()
+        return (logical != activeLow) to logical
+    }
+
     /**
      * Writes a value to the pin.
      *
@@ -19,10 +31,31 @@ interface GpioPin : AutoCloseable {
     fun write(value: Boolean)
 
     val mode: GpioIOMode
+
+    /**
+     * Whether the pin is currently configured as an input.
+     */
+    val isInput: Boolean
+        get() = mode == GpioIOMode.INPUT
+
+    /**
+     * Whether the pin is currently configured as an output.
+     */
+    val isOutput: Boolean
+        get() = mode == GpioIOMode.OUTPUT
+
     val activeLow: Boolean
     val bias: GpioLineBias
     val drive: GpioDriveMode
 
+    /**
+     * Reads the bias actually applied to the line back from the hardware, unlike [bias],
+     * which may only be the last value set.
+     *
+     * @return the applied bias, or `null` if the backend can't read it back.
+     */
+    fun readBias(): GpioLineBias? = null
+
     fun setMode(mode: GpioIOMode): GpioPin
     fun setActiveLow(activeLow: Boolean): GpioPin
     fun setBias(bias: GpioLineBias): GpioPin
@@ -31,10 +64,10 @@ interface GpioPin : AutoCloseable {
     /**
      * Resets the pin to its default state.
      */
-    fun reset(mode: GpioIOMode = GpioIOMode.INPUT) {
+    fun reset(mode: GpioIOMode = GpioIOMode.DEFAULT) {
         setMode(mode)
         setActiveLow(false)
-        setBias(GpioLineBias.NONE)
-        setDrive(GpioDriveMode.PUSH_PULL)
+        setBias(GpioLineBias.DEFAULT)
+        setDrive(GpioDriveMode.DEFAULT)
     }
 }
@@ -0,0 +1,70 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * Detects short presses, long presses and held-down repeats of a button.
+ *
+ * @param input The debounced button input, `true` meaning pressed.
+ * @param longPressMs How long the button has to be held down to count as a long press.
+ * @param repeatMs How often [PressEvent.REPEAT] is reported while the button is held after a long press,
+ * or `null` to disable repeats.
+ */
+class LongPressDetector(
+    val input: DebouncedInput,
+    val longPressMs: Long,
+    val repeatMs: Long? = null,
+) {
+    enum class PressEvent {
+        /**
+         * The button was released before [longPressMs] passed.
+         */
+        SHORT,
+
+        /**
+         * The button has been held down for [longPressMs].
+         * No [SHORT] event is reported on its release.
+         */
+        LONG,
+
+        /**
+         * The button is still held down, [repeatMs] after the previous [LONG] or [REPEAT] event.
+         */
+        REPEAT,
+    }
+
+    private var pressedSinceMs: Long? = null
+    private var lastEventMs: Long? = null
+
+    /**
+     * Polls the input and reports a press event, if any happened.
+     *
+     * @param nowMs The current time in milliseconds, from the same clock as used for the [input].
+     */
+    fun poll(nowMs: Long): PressEvent? {
+        val pressed = input.poll(nowMs)
+        val pressedSince = pressedSinceMs
+
+        if (pressedSince == null) {
+            if (pressed) pressedSinceMs = nowMs
+            return null
+        }
+
+        if (!pressed) {
+            val wasLong = lastEventMs != null
+            pressedSinceMs = null
+            lastEventMs = null
+            return if (wasLong) null else PressEvent.SHORT
+        }
+
+        val lastEvent = lastEventMs
+        if (lastEvent == null) {
+            if (nowMs - pressedSince >= longPressMs) {
+                lastEventMs = nowMs
+                return PressEvent.LONG
+            }
+        } else if (repeatMs != null && nowMs - lastEvent >= repeatMs) {
+            lastEventMs = nowMs
+            return PressEvent.REPEAT
+        }
+        return null
+    }
+}
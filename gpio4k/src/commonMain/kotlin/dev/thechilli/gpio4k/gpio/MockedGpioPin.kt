@@ -21,16 +21,24 @@ class MockedGpioPin(
     var internallyExpected: Boolean? = null
 
     fun getInternalState(): Boolean? {
+        var state = getDrivenState()
+        if(state == null) {
+            if(bias == GpioLineBias.PULL_UP) state = true
+            else if(bias == GpioLineBias.PULL_DOWN) state = false
+        }
+        return state
+    }
+
+    /**
+     * Like [getInternalState], but ignoring the bias, so `null` unless the pin actively drives the line.
+     */
+    private fun getDrivenState(): Boolean? {
         var state = internallyExpected
         if(state != null && activeLow) state = !state
         if(state == true && drive == GpioDriveMode.OPEN_DRAIN)
             state = null
         else if(state == false && drive == GpioDriveMode.OPEN_SOURCE)
             state = null
-        if(state == null) {
-            if(bias == GpioLineBias.PULL_UP) state = true
-            else if(bias == GpioLineBias.PULL_DOWN) state = false
-        }
         return state
     }
 
@@ -41,7 +49,10 @@ class MockedGpioPin(
     fun getFinalState(): Boolean? {
         val internalState = getInternalState()
 
-        if(internalState == null) return externalState
+        if(internalState == null || externalState == null) return internalState ?: externalState
+
+        // Pull resistors are weak, so anything driving the line externally overrides them
+        if(getDrivenState() == null) return externalState
 
         if(internalState != externalState) {
             throw GpioException("Internal state of pin $name is ${if(internalState) "HIGH" else "LOW"}, but external state is ${if(externalState!!) "HIGH" else "LOW"}. This is a short circuit!")
@@ -55,14 +66,15 @@ class MockedGpioPin(
             throw GpioException("Pin is not readable")
         }
 
-        if (externalState == null) {
+        // Takes the bias into account when nothing drives the line
+        var state = getFinalState()
+        if (state == null) {
             if (warnOnReadDangling) {
                 println("Warning: reading from pin $name with high impedance")
             }
             return Random.nextBoolean()
         }
 
-        var state = externalState!!
         if (activeLow)
             state = !state
         return state
@@ -76,18 +88,26 @@ class MockedGpioPin(
         internallyExpected = value
     }
 
-    override var mode: GpioIOMode = GpioIOMode.INPUT
+    override var mode: GpioIOMode = GpioIOMode.DEFAULT
         protected set
 
     override var activeLow: Boolean = false
         protected set
 
-    override var bias: GpioLineBias = GpioLineBias.NONE
+    override var bias: GpioLineBias = GpioLineBias.DEFAULT
         protected set
 
-    override var drive: GpioDriveMode = GpioDriveMode.PUSH_PULL
+    override var drive: GpioDriveMode = GpioDriveMode.DEFAULT
         protected set
 
+    /**
+     * The bias the simulated hardware actually applied, reported by [readBias] instead of [bias] if set,
+     * e.g. to simulate a pin without pull resistors.
+     */
+    var appliedBias: GpioLineBias? = null
+
+    override fun readBias(): GpioLineBias = appliedBias ?: bias
+
     override fun setMode(mode: GpioIOMode): GpioPin {
         this.mode = mode
         if(mode == GpioIOMode.INPUT) {
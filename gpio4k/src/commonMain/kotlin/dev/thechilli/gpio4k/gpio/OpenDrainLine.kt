@@ -0,0 +1,57 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * A bidirectional open-drain line built on a single [pin], following the I²C electrical model.
+ *
+ * The line is either actively pulled low, or released and left to a pull-up resistor, in which case any other device
+ * on the line can still pull it low. Releasing is done by switching the pin to input, so it works on every backend.
+ *
+ * Levels are electrical, so [GpioPin.activeLow] is cleared on the pin.
+ */
+class OpenDrainLine(
+    val pin: GpioPin,
+) {
+    /**
+     * Whether the line is currently pulled low by this side.
+     */
+    var isPulledLow: Boolean = false
+        private set
+
+    init {
+        pin.setActiveLow(false)
+        release()
+    }
+
+    /**
+     * Actively drives the line low.
+     */
+    fun pullLow() {
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(false)
+        isPulledLow = true
+    }
+
+    /**
+     * Stops driving the line, letting it float high unless another device pulls it low.
+     */
+    fun release() {
+        pin.setMode(GpioIOMode.INPUT)
+        isPulledLow = false
+    }
+
+    /**
+     * Sets the line to the given level: low pulls it down, high releases it.
+     */
+    fun set(high: Boolean) {
+        if (high) release() else pullLow()
+    }
+
+    /**
+     * Reads the current level of the line.
+     * While this side pulls the line low, it's always low.
+     */
+    fun read(): Boolean {
+        if (isPulledLow) return false
+        return pin.read()
+    }
+}
@@ -0,0 +1,83 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * A GPIO pin wrapper emulating [GpioDriveMode.OPEN_DRAIN] and [GpioDriveMode.OPEN_SOURCE] in software,
+ * for backends that only support push-pull outputs (like `SysFsGpioPin`).
+ *
+ * Whenever the output should be high impedance, the underlying pin is switched to input mode instead.
+ * The floating level then relies on an external (or internal, if [bias] is set) pull resistor.
+ * The bias is re-applied on every switch to input, as some backends drop it when the line changes direction.
+ *
+ * The underlying [pin] itself is always kept in [GpioDriveMode.PUSH_PULL].
+ */
+class SoftwareDriveGpioPin(
+    val pin: GpioPin,
+) : GpioPin {
+    init {
+        pin.setDrive(GpioDriveMode.PUSH_PULL)
+    }
+
+    override fun read(): Boolean {
+        if (mode != GpioIOMode.INPUT)
+            throw GpioException("Pin is not readable")
+        return pin.read()
+    }
+
+    override fun write(value: Boolean) {
+        if (mode != GpioIOMode.OUTPUT)
+            throw GpioException("Pin is not writable")
+
+        val electricallyHigh = value != activeLow
+        val floating = when (drive) {
+            GpioDriveMode.PUSH_PULL   -> false
+            GpioDriveMode.OPEN_DRAIN  -> electricallyHigh
+            GpioDriveMode.OPEN_SOURCE -> !electricallyHigh
+        }
+
+        if (floating) {
+            pin.setMode(GpioIOMode.INPUT)
+            pin.setBias(bias)
+        } else {
+            pin.setMode(GpioIOMode.OUTPUT)
+            pin.write(value)
+        }
+    }
+
+    override var mode: GpioIOMode = pin.mode
+        private set
+
+    override val activeLow: Boolean
+        get() = pin.activeLow
+
+    override var bias: GpioLineBias = pin.bias
+        private set
+
+    override var drive: GpioDriveMode = GpioDriveMode.DEFAULT
+        private set
+
+    override fun setMode(mode: GpioIOMode): SoftwareDriveGpioPin {
+        this.mode = mode
+        pin.setMode(mode)
+        return this
+    }
+
+    override fun setActiveLow(activeLow: Boolean): SoftwareDriveGpioPin {
+        pin.setActiveLow(activeLow)
+        return this
+    }
+
+    override fun setBias(bias: GpioLineBias): SoftwareDriveGpioPin {
+        this.bias = bias
+        pin.setBias(bias)
+        return this
+    }
+
+    override fun setDrive(drive: GpioDriveMode): SoftwareDriveGpioPin {
+        this.drive = drive
+        return this
+    }
+
+    override fun close() {
+        pin.close()
+    }
+}
@@ -0,0 +1,61 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.utils.Countdown
+
+/**
+ * An output that is activated for a limited time, e.g. a relay or a door latch solenoid.
+ *
+ * The pin is written logically, so active-low relays work as long as [GpioPin.activeLow] is set on the [pin].
+ * Closing the output always releases it, so it's safe to use with [dev.thechilli.gpio4k.utils.closingScope].
+ */
+class TimedOutput(
+    val pin: GpioPin,
+) : AutoCloseable {
+    init {
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(false)
+    }
+
+    private var countdown: Countdown? = null
+
+    val isActive: Boolean
+        get() = countdown != null
+
+    /**
+     * The time left until the output is released, in milliseconds, or `0` if it's not active.
+     */
+    val remainingMs: Long
+        get() = countdown?.remainingMs ?: 0
+
+    /**
+     * Activates the output for [durationMs] milliseconds.
+     * Activating an already active output restarts the timer.
+     */
+    fun activate(durationMs: Long) {
+        countdown = Countdown(durationMs)
+        pin.write(true)
+    }
+
+    /**
+     * Advances the timer by [elapsedMs] milliseconds and releases the output once it expires.
+     *
+     * @return `true` if the output is still active.
+     */
+    fun tick(elapsedMs: Long): Boolean {
+        val countdown = countdown ?: return false
+        if (countdown.tick(elapsedMs)) release()
+        return isActive
+    }
+
+    /**
+     * Releases the output immediately.
+     */
+    fun release() {
+        countdown = null
+        pin.write(false)
+    }
+
+    override fun close() {
+        release()
+    }
+}
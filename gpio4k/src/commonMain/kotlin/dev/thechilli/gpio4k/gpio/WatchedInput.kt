@@ -0,0 +1,43 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.utils.Event
+
+/**
+ * Watches a debounced [input] and invokes events whenever its level changes.
+ *
+ * Use a [DebouncedInput] with a debounce time of `0` to watch a pin without debouncing.
+ */
+class WatchedInput(
+    val input: DebouncedInput,
+) {
+    /**
+     * Invoked with the new level on every change.
+     */
+    val onChange: Event<Boolean> = Event()
+
+    /**
+     * Invoked when the level changes to `true`.
+     */
+    val onRise: Event<Unit> = Event()
+
+    /**
+     * Invoked when the level changes to `false`.
+     */
+    val onFall: Event<Unit> = Event()
+
+    private var lastValue = input.value
+
+    /**
+     * Polls the input and invokes the events if its level changed since the last poll.
+     *
+     * @param nowMs The current time in milliseconds, from any monotonic clock.
+     */
+    fun poll(nowMs: Long) {
+        val value = input.poll(nowMs)
+        if (value == lastValue) return
+
+        lastValue = value
+        onChange.invoke(value)
+        if (value) onRise.invoke(Unit) else onFall.invoke(Unit)
+    }
+}
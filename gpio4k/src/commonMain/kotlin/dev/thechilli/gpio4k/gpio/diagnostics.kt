@@ -0,0 +1,91 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.utils.sleepUs
+
+/**
+ * Checks the wiring between two pins by driving the [output] and reading it back through the [input].
+ *
+ * Requires a physical jumper connecting both pins, with nothing else driving the line.
+ * Both pins are compared by their electrical level, so their [GpioPin.activeLow] settings may differ.
+ *
+ * @param settleUs Time to wait after each write for the line to settle.
+ * @return `true` if the input followed the output both high and low.
+ */
+fun loopbackTest(output: GpioPin, input: GpioPin, settleUs: Int = 100): Boolean {
+    output.setMode(GpioIOMode.OUTPUT)
+    input.setMode(GpioIOMode.INPUT)
+
+    val inverted = output.activeLow != input.activeLow
+
+    for (value in listOf(true, false)) {
+        output.write(value)
+        sleepUs(settleUs)
+        if (input.read() != (value != inverted)) return false
+    }
+    return true
+}
+
+/**
+ * Checks whether nothing is driving an input [pin], e.g. a disconnected encoder, by seeing if it follows the bias.
+ *
+ * The pin is read with the pull-up and then with the pull-down. If it followed both, nothing overrides the pulls,
+ * so the pin is floating. The original mode and bias are restored afterward.
+ * Requires a backend supporting bias, and an external pull resistor on the line makes the pin look driven.
+ *
+ * @param settleUs Time to wait after each bias change for the line to settle.
+ * @return `true` if the pin is floating.
+ */
+fun detectFloating(pin: GpioPin, settleUs: Int = 100): Boolean {
+    val originalMode = pin.mode
+    val originalBias = pin.bias
+
+    pin.setMode(GpioIOMode.INPUT)
+    try {
+        pin.setBias(GpioLineBias.PULL_UP)
+        sleepUs(settleUs)
+        val followsPullUp = pin.read() != pin.activeLow
+
+        pin.setBias(GpioLineBias.PULL_DOWN)
+        sleepUs(settleUs)
+        val followsPullDown = pin.read() == pin.activeLow
+
+        return followsPullUp && followsPullDown
+    } finally {
+        pin.setBias(originalBias)
+        pin.setMode(originalMode)
+    }
+}
+
+/**
+ * Checks each conductor of a bus, e.g. a ribbon cable, by walking a single high level across the [outputs]
+ * and reading it back through the corresponding [inputs].
+ *
+ * Like [loopbackTest], it requires each output to be jumpered to the input at the same index, and compares
+ * electrical levels. A broken conductor fails its own pin, while a swapped or shorted pair fails both pins.
+ *
+ * @param settleUs Time to wait after each pattern for the lines to settle.
+ * @return whether each pin passed, in bus order.
+ */
+fun busLoopbackTest(outputs: List<GpioPin>, inputs: List<GpioPin>, settleUs: Int = 100): List<Boolean> {
+    require(outputs.size == inputs.size) { "Expected as many inputs as outputs, got ${inputs.size} and ${outputs.size}" }
+
+    outputs.forEach { it.setMode(GpioIOMode.OUTPUT) }
+    inputs.forEach { it.setMode(GpioIOMode.INPUT) }
+
+    val passed = MutableList(outputs.size) { true }
+    for (high in outputs.indices) {
+        for ((i, output) in outputs.withIndex()) {
+            // Write electrical levels, whatever the active level
+            output.write((i == high) != output.activeLow)
+        }
+        sleepUs(settleUs)
+
+        for ((i, input) in inputs.withIndex()) {
+            val electricallyHigh = input.read() != input.activeLow
+            if (electricallyHigh != (i == high)) passed[i] = false
+        }
+    }
+
+    outputs.forEach { it.write(it.activeLow) }
+    return passed
+}
@@ -0,0 +1,154 @@
+package dev.thechilli.gpio4k.i2c
+
+import dev.thechilli.gpio4k.gpio.GpioException
+import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.gpio.OpenDrainLine
+import dev.thechilli.gpio4k.utils.sleepUs
+import kotlin.time.TimeSource
+
+/**
+ * A software I²C master driving the [sda] and [scl] pins directly, for boards without hardware I²C exposed.
+ *
+ * Both lines are driven as [OpenDrainLine]s, so they need pull-up resistors, either external or internal.
+ * Slaves holding SCL low (clock stretching) are waited for, up to [stretchTimeoutMs].
+ *
+ * The achievable clock rate is limited by the backend, so [halfPeriodUs] is only a lower bound on the timing.
+ *
+ * @param halfPeriodUs Time to hold each clock phase. The default of 5 µs gives at most 100 kHz (standard mode).
+ */
+class I2cBitbang(
+    sda: GpioPin,
+    scl: GpioPin,
+    val halfPeriodUs: Int = 5,
+    val stretchTimeoutMs: Int = 10,
+) {
+    private val sda = OpenDrainLine(sda)
+    private val scl = OpenDrainLine(scl)
+
+    /**
+     * Writes [data] to the device at the 7-bit [address].
+     *
+     * @throws GpioException if the device doesn't acknowledge the address or any of the bytes.
+     */
+    fun write(address: UByte, data: UByteArray) {
+        transaction(address, read = false) {
+            for ((index, byte) in data.withIndex()) {
+                if (!writeByte(byte))
+                    throw GpioException("Device 0x${address.toString(16)} didn't acknowledge byte $index")
+            }
+        }
+    }
+
+    /**
+     * Reads [count] bytes from the device at the 7-bit [address].
+     *
+     * @throws GpioException if the device doesn't acknowledge the address.
+     */
+    fun read(address: UByte, count: Int): UByteArray {
+        require(count >= 0) { "Byte count must not be negative" }
+        var data = UByteArray(0)
+        transaction(address, read = true) {
+            // The last byte is not acknowledged, to tell the device to stop sending
+            data = UByteArray(count) { readByte(ack = it < count - 1) }
+        }
+        return data
+    }
+
+    private inline fun transaction(address: UByte, read: Boolean, block: () -> Unit) {
+        require(address < 0x80u) { "Address must be 7-bit" }
+
+        start()
+        try {
+            val addressByte = (address.toInt() shl 1 or if (read) 1 else 0).toUByte()
+            if (!writeByte(addressByte))
+                throw GpioException("Device 0x${address.toString(16)} didn't acknowledge its address")
+            block()
+        } finally {
+            stop()
+        }
+    }
+
+    /**
+     * Sends a start condition: SDA falls while SCL is high.
+     * Also usable as a repeated start in the middle of a transaction.
+     */
+    fun start() {
+        sda.release()
+        delay()
+        releaseClock()
+        delay()
+        sda.pullLow()
+        delay()
+        scl.pullLow()
+    }
+
+    /**
+     * Sends a stop condition: SDA rises while SCL is high.
+     */
+    fun stop() {
+        sda.pullLow()
+        delay()
+        releaseClock()
+        delay()
+        sda.release()
+        delay()
+    }
+
+    /**
+     * Sends a byte, most significant bit first.
+     *
+     * @return whether the device acknowledged it.
+     */
+    fun writeByte(byte: UByte): Boolean {
+        for (i in 7 downTo 0) {
+            writeBit((byte.toInt() shr i) and 1 == 1)
+        }
+        return !readBit()
+    }
+
+    /**
+     * Receives a byte, most significant bit first, then acknowledges it if [ack] is set.
+     */
+    fun readByte(ack: Boolean): UByte {
+        var byte = 0
+        repeat(8) {
+            byte = byte shl 1 or if (readBit()) 1 else 0
+        }
+        writeBit(!ack)
+        return byte.toUByte()
+    }
+
+    private fun writeBit(bit: Boolean) {
+        sda.set(bit)
+        delay()
+        releaseClock()
+        delay()
+        scl.pullLow()
+    }
+
+    private fun readBit(): Boolean {
+        sda.release()
+        delay()
+        releaseClock()
+        delay()
+        val bit = sda.read()
+        scl.pullLow()
+        return bit
+    }
+
+    /**
+     * Releases SCL and waits for it to go high, in case a slave is stretching the clock.
+     *
+     * @throws GpioException.Timeout if SCL stays low for longer than [stretchTimeoutMs].
+     */
+    private fun releaseClock() {
+        scl.release()
+        val start = TimeSource.Monotonic.markNow()
+        while (!scl.read()) {
+            if (start.elapsedNow().inWholeMilliseconds >= stretchTimeoutMs)
+                throw GpioException.Timeout("SCL held low for more than $stretchTimeoutMs ms")
+        }
+    }
+
+    private fun delay() = sleepUs(halfPeriodUs)
+}
@@ -14,6 +14,27 @@ interface CharacterDisplay {
             }}
     }
 
+    /**
+     * Moves the cursor to the start of the given [row] and prints a string there.
+     *
+     * Characters missing from the display's character set are not translated and may show up as blanks.
+     */
+    fun printLine(row: Int, str: String) {
+        require(row in 0 until rows) { "Row must be between 0 and ${rows - 1}" }
+        setCursor(row, 0)
+        print(str)
+    }
+
+    /**
+     * Clears a single [row] by filling it with spaces, then moves the cursor to its start.
+     *
+     * Unlike [clearDisplay], the rest of the display is left untouched.
+     */
+    fun clearLine(row: Int) {
+        printLine(row, " ".repeat(columns))
+        setCursor(row, 0)
+    }
+
     /**
      * Writes a character to the display.
      */
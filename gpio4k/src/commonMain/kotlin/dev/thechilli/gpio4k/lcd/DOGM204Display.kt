@@ -1,5 +1,7 @@
 package dev.thechilli.gpio4k.lcd
 
+import kotlin.math.abs
+
 interface DOGM204Display : HD44780Display {
     override fun clearDisplay() {
         writeData(false, 0x01u, null, null)
@@ -57,6 +59,40 @@ interface DOGM204Display : HD44780Display {
         BIAS_1_3(true, true)
     }
 
+    /**
+     * Temperature coefficient of the LCD driving voltage, from the SSD1803A datasheet, in percent per °C.
+     * The voltage drops by [percentPerDegree] for every degree the controller warms up, compensating the liquid
+     * crystal getting easier to drive, so the contrast stays the same.
+     *
+     * | Setting  | TC2–TC0 | Drift     |
+     * |----------|---------|-----------|
+     * | TC_0_00  | 000     | 0 %/°C    |
+     * | TC_0_05  | 010     | −0.05 %/°C (default) |
+     * | TC_0_10  | 001     | −0.10 %/°C |
+     * | TC_0_15  | 011     | −0.15 %/°C |
+     * | TC_0_20  | 100     | −0.20 %/°C |
+     * | TC_0_25  | 101     | −0.25 %/°C |
+     * | TC_0_30  | 110     | −0.30 %/°C |
+     * | TC_0_35  | 111     | −0.35 %/°C |
+     */
+    enum class DOGM204TemperatureCoefficient(val tc: UByte, val percentPerDegree: Float) {
+        TC_0_00(0b000u, 0f),
+        TC_0_05(0b010u, -0.05f),
+        TC_0_10(0b001u, -0.10f),
+        TC_0_15(0b011u, -0.15f),
+        TC_0_20(0b100u, -0.20f),
+        TC_0_25(0b101u, -0.25f),
+        TC_0_30(0b110u, -0.30f),
+        TC_0_35(0b111u, -0.35f);
+
+        companion object {
+            /**
+             * The coefficient closest to the given drift of the driving voltage, in percent per °C.
+             */
+            fun nearest(percentPerDegree: Float) = values().minBy { abs(it.percentPerDegree - percentPerDegree) }
+        }
+    }
+
     fun doubleHeightBiasShift(
         doubleHeightConfiguration: DOGM204DoubleHeightConfiguration,
         bs1: Boolean,
@@ -85,7 +121,7 @@ interface DOGM204Display : HD44780Display {
     /**
      * @param frequencyHz One of the supported frequencies: 420, 460, 500, 540 (default), 580, 620, 640, 680.
      */
-    fun configureOscillatorFrequency(bs0: Boolean, frequencyHz: Int) {
+    fun configureOscillatorFrequency(bs0: Boolean, frequencyHz: Int = DEFAULT_OSCILLATOR_FREQUENCY_HZ) {
         val data = 0x10u or
                 (if (bs0) 0x08u else 0u) or
                 getFrequencyId(frequencyHz).toUInt()
@@ -134,7 +170,10 @@ interface DOGM204Display : HD44780Display {
         writeData(false, data.toUByte(), false, true)
     }
 
-    fun followerControl(divider: Boolean, internalResistorRatio: Int) {
+    /**
+     * @param internalResistorRatio Internal resistor ratio setting, IR0–IR7. Defaults to the DOGM204's recommended IR6.
+     */
+    fun followerControl(divider: Boolean = true, internalResistorRatio: Int = DEFAULT_INTERNAL_RESISTOR_RATIO) {
         require(internalResistorRatio in 0..7) { "Internal resistor ratio setting must be between 0 and 7, " +
                 "corresponding to IR0–IR7 from the documentation." }
 
@@ -184,6 +223,17 @@ interface DOGM204Display : HD44780Display {
     fun writeData(rs: Boolean, data: UByte, reBit: Boolean?, isBit: Boolean?)
 
     companion object {
+        const val DEFAULT_OSCILLATOR_FREQUENCY_HZ = 540
+        const val DEFAULT_INTERNAL_RESISTOR_RATIO = 0b110
+
+        /**
+         * DDRAM addresses of the start of each line, depending on the number of lines.
+         */
+        val LINE_OFFSETS_1: List<UByte> = listOf(0x00u)
+        val LINE_OFFSETS_2: List<UByte> = listOf(0x00u, 0x40u)
+        val LINE_OFFSETS_3: List<UByte> = listOf(0x00u, 0x20u, 0x40u)
+        val LINE_OFFSETS_4: List<UByte> = listOf(0x00u, 0x20u, 0x40u, 0x60u)
+
         private const val NUL = '\u0000'
 
         val ROM_A = HD44780CharacterSet.of(
@@ -4,11 +4,23 @@ import dev.thechilli.gpio4k.gpio.GpioIOMode
 import dev.thechilli.gpio4k.gpio.GpioIOMode.INPUT
 import dev.thechilli.gpio4k.gpio.GpioIOMode.OUTPUT
 import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.gpio.writeByteAsNibbles
 import dev.thechilli.gpio4k.utils.bitFromRight
 import dev.thechilli.gpio4k.utils.sleepMs
 import dev.thechilli.gpio4k.utils.sleepUs
 import kotlin.math.roundToInt
 
+/**
+ * @param resetPin Reset pin.
+ * @param rsPin Register select pin.
+ * @param rwPin Read/write pin. If null, the display is write-only.
+ * @param enablePin Enable pin.
+ * @param dataPins Data pins. The number of pins must be 4 or 8.
+ * @param rows Number of rows on the display.
+ * @param columns Number of columns on the display.
+ * @param ownsPins Whether the display takes ownership of the pins, closing them when it's closed.
+ * Otherwise, the caller is responsible for closing the pins and closing the display does nothing.
+ */
 open class DirectDOGM204Display(
     protected val resetPin: GpioPin,
     protected val rsPin: GpioPin,
@@ -17,7 +29,8 @@ open class DirectDOGM204Display(
     protected val dataPins: List<GpioPin>,
     rows: Int,
     columns: Int,
-) : DOGM204Display {
+    protected val ownsPins: Boolean = false,
+) : DOGM204Display, AutoCloseable {
     init {
         require(dataPins.size == 4 || dataPins.size == 8) { "Data pins must be 4 or 8" }
         require(rows in setOf(1, 2, 4)) { "Unsupported number of rows: $rows" }
@@ -42,7 +55,7 @@ open class DirectDOGM204Display(
     var bias: DOGM204Display.DOGM204Bias = DOGM204Display.DOGM204Bias.BIAS_1_3
         protected set
 
-    var oscillatorFrequency = 540
+    var oscillatorFrequency = DOGM204Display.DEFAULT_OSCILLATOR_FREQUENCY_HZ
         protected set
 
     var contrast = 0b11010.toFloat() / 0b11111
@@ -65,25 +78,78 @@ open class DirectDOGM204Display(
         // 00011011
         configureOscillatorFrequency(bias.bs0, oscillatorFrequency)
         // 01101110
-        followerControl(true, 0b110)
+        followerControl()
         // 01010111
-        val contrastAsByte = (contrast * 0b11111).roundToInt().toUByte()
-        iconContrastControl(false, true, contrastAsByte)
         // 01110010
-        contrastPreciseSet(contrastAsByte)
+        sendContrast()
         // 00111000
         // 00001111
         displayControl(true, true, true)
     }
 
+    /**
+     * Sets the display contrast, between 0 and 1.
+     */
+    fun setContrast(contrast: Float) {
+        require(contrast in 0f..1f) { "Contrast must be between 0 and 1" }
+        this.contrast = contrast
+        sendContrast()
+    }
+
+    /**
+     * The temperature coefficient last set with [applyTemperatureCompensation], or the controller's default.
+     */
+    var temperatureCoefficient = DOGM204Display.DOGM204TemperatureCoefficient.TC_0_05
+        protected set
+
+    /**
+     * Compensates for the ambient temperature, as the liquid crystal needs a higher driving voltage when cold.
+     *
+     * The controller's temperature coefficient is set to the one nearest to [slopePercentPerDegree], the drift of
+     * the driving voltage in percent per °C, see [DOGM204Display.DOGM204TemperatureCoefficient] for the settings.
+     * The controller applies it on its own, using its internal temperature.
+     *
+     * Optionally, the contrast is nudged too, for displays the coefficient alone doesn't keep readable:
+     * the contrast set with [setContrast] is used as-is at [referenceCelsius], and is raised by [contrastPerDegree]
+     * for every degree below it (lowered for every degree above). The temperature has to be measured elsewhere.
+     *
+     * @return the coefficient applied.
+     */
+    fun applyTemperatureCompensation(
+        celsius: Float,
+        slopePercentPerDegree: Float = DOGM204Display.DOGM204TemperatureCoefficient.TC_0_05.percentPerDegree,
+        referenceCelsius: Float = 25f,
+        contrastPerDegree: Float = 0f,
+    ): DOGM204Display.DOGM204TemperatureCoefficient {
+        val coefficient = DOGM204Display.DOGM204TemperatureCoefficient.nearest(slopePercentPerDegree)
+        temperatureCoefficientControl(coefficient.tc)
+        temperatureCoefficient = coefficient
+
+        if (contrastPerDegree != 0f) {
+            val compensated = contrast + (referenceCelsius - celsius) * contrastPerDegree
+            sendContrast(compensated.coerceIn(0f, 1f))
+        }
+        return coefficient
+    }
+
+    private fun sendContrast(contrast: Float = this.contrast) {
+        val contrastAsByte = (contrast * 0b11111).roundToInt().toUByte()
+        iconContrastControl(false, true, contrastAsByte)
+        contrastPreciseSet(contrastAsByte)
+    }
+
     protected fun synchronize4Bit() {
         // https://en.wikipedia.org/wiki/Hitachi_HD44780_LCD_controller#Mode_selection
+        // Only the high nibble is wired, so each of these is a single enable pulse
+        setDataPinsMode(OUTPUT)
+        rwPin?.write(false)
+        rsPin.write(false)
         // Make sure to switch to 8-bit data length
-        writeData8Bit(0b0011u)
-        writeData8Bit(0b0011u)
-        writeData8Bit(0b0011u)
+        writeData8Bit(0b0011_0000u)
+        writeData8Bit(0b0011_0000u)
+        writeData8Bit(0b0011_0000u)
         // Switch to 4-bit data length
-        writeData8Bit(0b0010u)
+        writeData8Bit(0b0010_0000u)
     }
 
     override var columns: Int = columns
@@ -128,10 +194,17 @@ open class DirectDOGM204Display(
             }
         }
 
+    /**
+     * Whether [clearDisplay] re-sends the tracked cursor visibility and blinking afterwards,
+     * so they stay as set even if the controller resets them on clear.
+     */
+    var restoreDisplayControlOnClear = true
+
     override fun clearDisplay() {
         addressSpace = AddressSpace.DDRAM
         currentAddress = 0u
         super.clearDisplay()
+        if (restoreDisplayControlOnClear) displayControl(true, cursorVisible, cursorBlink)
     }
 
     override fun returnHome() {
@@ -188,10 +261,10 @@ open class DirectDOGM204Display(
 
     override val getLineOffsets: List<UByte>
         get() = when (rows) {
-            1 -> listOf(0x00u)
-            2 -> listOf(0x00u, 0x40u)
-            3 -> listOf(0x00u, 0x20u, 0x40u)
-            4 -> listOf(0x00u, 0x10u, 0x20u, 0x30u)
+            1 -> DOGM204Display.LINE_OFFSETS_1
+            2 -> DOGM204Display.LINE_OFFSETS_2
+            3 -> DOGM204Display.LINE_OFFSETS_3
+            4 -> DOGM204Display.LINE_OFFSETS_4
             else -> throw IllegalArgumentException("Unsupported number of rows: $rows")
         }
 
@@ -236,8 +309,48 @@ open class DirectDOGM204Display(
         super.displayControl(displayOn, cursorOn, cursorBlink)
     }
 
+    /**
+     * Whether the 6-dot wide font is used, as last set with [extendedFunctionSet].
+     */
+    var widerFont = false
+        private set
+
+    /**
+     * Whether the cursor is shown inverted, as last set with [extendedFunctionSet].
+     */
+    var cursorInvert = false
+        private set
+
+    /**
+     * Whether the controller is in 3 or 4 line mode, as last set with [extendedFunctionSet].
+     */
+    var threeOrFourLines = false
+        private set
+
+    override fun extendedFunctionSet(widerFont: Boolean, cursorInvert: Boolean, nw: Boolean) {
+        this.widerFont = widerFont
+        this.cursorInvert = cursorInvert
+        threeOrFourLines = nw
+        super.extendedFunctionSet(widerFont, cursorInvert, nw)
+    }
+
+    /**
+     * Switches between the 5-dot and the 6-dot wide font, keeping the other extended function set bits.
+     */
+    fun setFontWidth(wide: Boolean) {
+        extendedFunctionSet(wide, cursorInvert, threeOrFourLines)
+    }
+
+    /**
+     * The mode the data pins were last switched to, to avoid reconfiguring them on every transfer.
+     * Assumes nothing else changes the mode of the data pins.
+     */
+    private var dataPinsMode: GpioIOMode? = null
+
     private fun setDataPinsMode(mode: GpioIOMode) {
+        if (dataPinsMode == mode) return
         dataPins.forEach { it.setMode(mode) }
+        dataPinsMode = mode
     }
 
     protected var reBitOn = false
@@ -309,47 +422,49 @@ open class DirectDOGM204Display(
     }
 
     private fun writeData4Bit(data: UByte) {
-        for ((i, pin) in dataPins.withIndex()) {
-            pin.write(data.bitFromRight(i + 4))
+        dataPins.writeByteAsNibbles(data) {
+            sleepUs(1)
+            enablePin.write(true)
+            sleepUs(1)
+            enablePin.write(false)
+            sleepUs(1)
         }
-
-        sleepUs(1)
-        enablePin.write(true)
-        sleepUs(1)
-        enablePin.write(false)
-        sleepUs(1)
-
-        for ((i, pin) in dataPins.withIndex()) {
-            pin.write(data.bitFromRight(i))
-        }
-
-        sleepUs(1)
-        enablePin.write(true)
-        sleepUs(1)
-        enablePin.write(false)
         sleepUs(1500)
     }
 
+    /**
+     * Reads a byte from the display. With 4 data pins, it takes two transfers, the high nibble first.
+     */
     override fun readData(rs: Boolean): UByte {
-        if (is4BitMode) TODO("4-bit reading is not implemented yet!")
-
         // Make sure the pins are in input mode
         setDataPinsMode(INPUT)
 
         rwPin?.write(true)
         rsPin.write(rs)
 
+        val output = if (!is4BitMode) {
+            readDataPulse()
+        } else {
+            readDataPulse() shl 4 or readDataPulse()
+        }
+        return output.toUByte()
+    }
+
+    /**
+     * Reads the data pins during a single enable pulse, the first pin being the most significant bit.
+     */
+    private fun readDataPulse(): Int {
         sleepMs(1)
         enablePin.write(true)
         sleepMs(1)
-        var output: UByte = 0u
-        for ((i, pin) in dataPins.withIndex()) {
-            if (pin.read())
-                output = output or (1u shl i).toUByte()
-        }
+        val value = dataPins.fold(0) { acc, pin -> acc shl 1 or if (pin.read()) 1 else 0 }
         enablePin.write(false)
         sleepMs(2)
+        return value
+    }
 
-        return output
+    override fun close() {
+        if (!ownsPins) return
+        (listOfNotNull(resetPin, rsPin, rwPin, enablePin) + dataPins).forEach { it.close() }
     }
 }
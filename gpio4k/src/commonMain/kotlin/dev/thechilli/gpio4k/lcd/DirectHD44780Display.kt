@@ -4,6 +4,7 @@ import dev.thechilli.gpio4k.gpio.GpioIOMode
 import dev.thechilli.gpio4k.gpio.GpioIOMode.INPUT
 import dev.thechilli.gpio4k.gpio.GpioIOMode.OUTPUT
 import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.gpio.writeByteAsNibbles
 import dev.thechilli.gpio4k.utils.bitFromRight
 import dev.thechilli.gpio4k.utils.sleepMs
 import dev.thechilli.gpio4k.utils.sleepUs
@@ -37,21 +38,34 @@ open class DirectHD44780Display(
     }
 
     override fun initialize() {
-        if (is4BitMode) synchronize4Bit()
+        synchronize()
         functionSet(!is4BitMode, rows > 1, font5x10)
         clearDisplay()
         displayControl(true, _cursorVisible, _cursorBlink)
         entryModeSet(increment = true, shift = false)
     }
 
-    protected fun synchronize4Bit() {
+    /**
+     * Puts the controller into 8-bit mode from any state, including halfway through a 4-bit transfer,
+     * then switches to 4-bit mode if only 4 data pins are wired.
+     *
+     * Only the upper 4 data lines are read during this sequence, so each write is a single enable pulse in both modes.
+     */
+    protected fun synchronize() {
         // https://en.wikipedia.org/wiki/Hitachi_HD44780_LCD_controller#Mode_selection
+        setDataPinsMode(OUTPUT)
+        rwPin?.write(false)
+        rsPin.write(false)
+        // Wait for the power supply to settle
+        sleepMs(40)
+
         // Make sure to switch to 8-bit data length
-        writeData8Bit(0b0011u)
-        writeData8Bit(0b0011u)
-        writeData8Bit(0b0011u)
+        writeData8Bit(0b0011_0000u)
+        sleepMs(5)
+        writeData8Bit(0b0011_0000u)
+        writeData8Bit(0b0011_0000u)
         // Switch to 4-bit data length
-        writeData8Bit(0b0010u)
+        if (is4BitMode) writeData8Bit(0b0010_0000u)
     }
 
     override var columns: Int = columns
@@ -176,8 +190,16 @@ open class DirectHD44780Display(
         super.displayControl(displayOn, cursorOn, cursorBlink)
     }
 
+    /**
+     * The mode the data pins were last switched to, to avoid reconfiguring them on every transfer.
+     * Assumes nothing else changes the mode of the data pins.
+     */
+    private var dataPinsMode: GpioIOMode? = null
+
     private fun setDataPinsMode(mode: GpioIOMode) {
+        if (dataPinsMode == mode) return
         dataPins.forEach { it.setMode(mode) }
+        dataPinsMode = mode
     }
 
     override fun writeData(rs: Boolean, data: UByte) {
@@ -205,6 +227,9 @@ open class DirectHD44780Display(
         }
     }
 
+    /**
+     * Writes [data] in a single enable pulse. With 4 data pins, only the high nibble is sent.
+     */
     private fun writeData8Bit(data: UByte) {
         for ((i, pin) in dataPins.withIndex()) {
             pin.write(data.bitFromRight(i))
@@ -218,47 +243,44 @@ open class DirectHD44780Display(
     }
 
     private fun writeData4Bit(data: UByte) {
-        for ((i, pin) in dataPins.withIndex()) {
-            pin.write(data.bitFromRight(i + 4))
-        }
-
-        sleepUs(1)
-        enablePin.write(true)
-        sleepUs(1)
-        enablePin.write(false)
-        sleepUs(1)
-
-        for ((i, pin) in dataPins.withIndex()) {
-            pin.write(data.bitFromRight(i))
+        dataPins.writeByteAsNibbles(data) {
+            sleepUs(1)
+            enablePin.write(true)
+            sleepUs(1)
+            enablePin.write(false)
+            sleepUs(1)
         }
-
-        sleepUs(1)
-        enablePin.write(true)
-        sleepUs(1)
-        enablePin.write(false)
         sleepUs(1500)
     }
 
+    /**
+     * Reads a byte from the display. With 4 data pins, it takes two transfers, the high nibble first.
+     */
     override fun readData(rs: Boolean): UByte {
-        if (is4BitMode) TODO("4-bit reading is not implemented yet!")
-
         // Make sure the pins are in input mode
         setDataPinsMode(INPUT)
 
         rwPin?.write(true)
         rsPin.write(rs)
 
+        val output = if (!is4BitMode) {
+            readDataPulse()
+        } else {
+            readDataPulse() shl 4 or readDataPulse()
+        }
+        return output.toUByte()
+    }
+
+    /**
+     * Reads the data pins during a single enable pulse, the first pin being the most significant bit.
+     */
+    private fun readDataPulse(): Int {
         sleepMs(1)
         enablePin.write(true)
         sleepMs(1)
-        var output: UByte = 0u
-        for ((i, pin) in dataPins.withIndex()) {
-            if (pin.read())
-                output = output or (1u shl i).toUByte()
-        }
+        val value = dataPins.fold(0) { acc, pin -> acc shl 1 or if (pin.read()) 1 else 0 }
         enablePin.write(false)
         sleepMs(2)
-
-        return output
+        return value
     }
 }
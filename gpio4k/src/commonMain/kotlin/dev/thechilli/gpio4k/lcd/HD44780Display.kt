@@ -1,6 +1,8 @@
 package dev.thechilli.gpio4k.lcd
 
-import dev.thechilli.gpio4k.utils.bitFromLeft
+import dev.thechilli.gpio4k.gpio.GpioException
+import dev.thechilli.gpio4k.utils.bitFromRight
+import dev.thechilli.gpio4k.utils.sleepMs
 
 interface HD44780Display : CharacterDisplay {
     val getLineOffsets: List<UByte>
@@ -106,14 +108,47 @@ interface HD44780Display : CharacterDisplay {
     }
 
     override fun readBusyFlag(): Boolean {
-        return readBusyAndAddress().bitFromLeft(0)
+        return readBusyAndAddress().bitFromRight(0)
     }
 
     override fun readAddress(): UByte {
         return readBusyAndAddress() and 0b0111_1111u
     }
 
+    /**
+     * Initializes the display, then checks that the controller responds, turning wiring mistakes into an error
+     * instead of a blank display.
+     *
+     * The check waits for the busy flag to clear, then moves the cursor and reads the address back,
+     * so it requires the R/W pin to be wired. It works in both 8-bit and 4-bit mode, where every read takes
+     * two transfers.
+     *
+     * @throws GpioException if the controller stays busy or reports a different address.
+     */
+    fun initializeChecked() {
+        check(readingAvailable) { "Checked initialization requires the R/W pin" }
+
+        initialize()
+
+        var attempts = 0
+        while (readBusyFlag()) {
+            if (++attempts >= 10) throw GpioException("LCD not responding: busy flag stuck")
+            sleepMs(1)
+        }
+
+        val testAddress: UByte = 0x05u
+        setDdRamAddress(testAddress)
+        val address = readAddress()
+        setDdRamAddress(0u)
+        if (address != testAddress)
+            throw GpioException("LCD not responding: address $address read back instead of $testAddress")
+    }
+
     fun writeData(rs: Boolean, data: UByte)
+
+    /**
+     * Reads a byte from the display, the most significant bit coming from the first data pin, like in [writeData].
+     */
     fun readData(rs: Boolean): UByte
 
     companion object {
@@ -0,0 +1,62 @@
+package dev.thechilli.gpio4k.lcd
+
+/**
+ * A high-level facade over a [DirectDOGM204Display], running the whole setup sequence in [Builder.build].
+ *
+ * The wrapped display is still accessible through [raw] for anything not covered here.
+ */
+class Lcd private constructor(
+    val raw: DirectDOGM204Display,
+) {
+    /**
+     * Prints [text] starting at the given position.
+     */
+    fun printAt(row: Int, column: Int, text: String) {
+        raw.setCursor(row, column)
+        raw.print(text)
+    }
+
+    /**
+     * Clears the display and moves the cursor home.
+     */
+    fun clear() {
+        raw.clearDisplay()
+    }
+
+    /**
+     * Configures and initializes an [Lcd].
+     *
+     * The defaults match the DOGM204: default contrast, with the cursor hidden.
+     */
+    class Builder(
+        private val display: DirectDOGM204Display,
+    ) {
+        private var contrast: Float? = null
+        private var cursorVisible = false
+        private var cursorBlink = false
+
+        /**
+         * Sets the contrast, between 0 and 1. If not called, the display keeps its default contrast.
+         */
+        fun contrast(contrast: Float) = apply {
+            require(contrast in 0f..1f) { "Contrast must be between 0 and 1" }
+            this.contrast = contrast
+        }
+
+        fun cursor(visible: Boolean, blink: Boolean = false) = apply {
+            cursorVisible = visible
+            cursorBlink = blink
+        }
+
+        /**
+         * Initializes the display with the configured settings and clears it.
+         */
+        fun build(): Lcd {
+            display.initialize()
+            contrast?.let { display.setContrast(it) }
+            display.displayControl(true, cursorVisible, cursorBlink)
+            display.clearDisplay()
+            return Lcd(display)
+        }
+    }
+}
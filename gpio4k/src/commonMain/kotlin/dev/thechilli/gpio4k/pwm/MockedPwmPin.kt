@@ -0,0 +1,46 @@
+package dev.thechilli.gpio4k.pwm
+
+class MockedPwmPin(
+    val name: String,
+) : PwmPin {
+    override var enabled: Boolean = false
+        private set
+
+    override fun enable() {
+        enabled = true
+    }
+
+    override fun disable() {
+        enabled = false
+    }
+
+    override var periodNs: Long = 0
+        private set
+
+    override var dutyCycleNs: Long = 0
+        private set
+
+    override var activeLow: Boolean = false
+        private set
+
+    override fun setPeriodNs(periodNs: Long): MockedPwmPin {
+        require(periodNs >= 0) { "Period of pin $name must not be negative" }
+        this.periodNs = periodNs
+        return this
+    }
+
+    override fun setDutyCycleNs(dutyCycleNs: Long): MockedPwmPin {
+        require(dutyCycleNs >= 0) { "Duty cycle of pin $name must not be negative" }
+        this.dutyCycleNs = dutyCycleNs
+        return this
+    }
+
+    override fun setActiveLow(activeLow: Boolean): MockedPwmPin {
+        this.activeLow = activeLow
+        return this
+    }
+
+    override fun close() {
+        // Nothing to do
+    }
+}
@@ -0,0 +1,45 @@
+package dev.thechilli.gpio4k.pwm
+
+/**
+ * Several PWM [pins] driven as a unit, e.g. the channels of an RGB LED.
+ */
+class PwmGroup(
+    val pins: List<PwmPin>,
+) {
+    init {
+        require(pins.isNotEmpty()) { "PWM group must have at least one pin" }
+    }
+
+    /**
+     * Sets the duty cycle of each pin to the corresponding fraction of its period, in pin order.
+     */
+    fun setDuties(ratios: List<Double>): PwmGroup {
+        require(ratios.size == pins.size) { "Expected ${pins.size} duty ratios, got ${ratios.size}" }
+        require(ratios.all { it in 0.0..1.0 }) { "Duty ratios must be between 0.0 and 1.0" }
+
+        for ((pin, ratio) in pins.zip(ratios)) {
+            pin.setRatio(ratio)
+        }
+        return this
+    }
+
+    /**
+     * Sets a common frequency on all pins, keeping each one's current duty ratio.
+     * Pins without a period set yet start at a ratio of 0.
+     */
+    fun setFrequencyHz(frequencyHz: Double): PwmGroup {
+        for (pin in pins) {
+            val ratio = if (pin.periodNs > 0) pin.ratio else 0.0
+            pin.setFrequency(frequencyHz, ratio)
+        }
+        return this
+    }
+
+    fun enable() {
+        pins.forEach { it.enable() }
+    }
+
+    fun disable() {
+        pins.forEach { it.disable() }
+    }
+}
@@ -1,12 +1,26 @@
 package dev.thechilli.gpio4k.pwm
 
+import kotlin.time.Duration
+import kotlin.time.Duration.Companion.nanoseconds
+
 /**
  * Generic PWM pin interface.
+ *
+ * The period and duty cycle are independent of whether the pin is enabled: they can be set while disabled,
+ * and disabling then enabling the pin resumes the same signal without setting them again.
+ * Only [reset] restores the defaults.
  */
 interface PwmPin : AutoCloseable {
     val enabled: Boolean
 
+    /**
+     * Starts outputting the signal with the current period and duty cycle.
+     */
     fun enable()
+
+    /**
+     * Stops outputting the signal, keeping the period and duty cycle.
+     */
     fun disable()
 
     /**
@@ -19,6 +33,20 @@ interface PwmPin : AutoCloseable {
      * Duty cycle is the time the signal is high in a period.
      */
     val dutyCycleNs: Long
+    /**
+     * The period of the PWM signal.
+     *
+     * @see periodNs
+     */
+    val period: Duration
+        get() = periodNs.nanoseconds
+    /**
+     * The duty cycle of the PWM signal.
+     *
+     * @see dutyCycleNs
+     */
+    val dutyCycle: Duration
+        get() = dutyCycleNs.nanoseconds
     /**
      * The ratio of the duty cycle to the period.
      */
@@ -36,6 +64,24 @@ interface PwmPin : AutoCloseable {
      * Duty cycle is the time the signal is high in a period.
      */
     fun setDutyCycleNs(dutyCycleNs: Long): PwmPin
+    /**
+     * Sets the period of the PWM signal, truncated to whole nanoseconds.
+     *
+     * @see setPeriodNs
+     */
+    fun setPeriod(period: Duration): PwmPin {
+        require(period.isFinite() && !period.isNegative()) { "Period must be finite and not negative" }
+        return setPeriodNs(period.inWholeNanoseconds)
+    }
+    /**
+     * Sets the duty cycle of the PWM signal, truncated to whole nanoseconds.
+     *
+     * @see setDutyCycleNs
+     */
+    fun setDutyCycle(dutyCycle: Duration): PwmPin {
+        require(dutyCycle.isFinite() && !dutyCycle.isNegative()) { "Duty cycle must be finite and not negative" }
+        return setDutyCycleNs(dutyCycle.inWholeNanoseconds)
+    }
     /**
      * Sets the duty cycle so that the ratio of the duty cycle to the period is equal to the given [ratio].
      */
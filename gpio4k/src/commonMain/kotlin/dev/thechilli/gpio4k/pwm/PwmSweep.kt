@@ -0,0 +1,66 @@
+package dev.thechilli.gpio4k.pwm
+
+import kotlin.math.pow
+
+/**
+ * A frequency sweep (glissando) on a PWM pin, e.g. for a startup chime.
+ *
+ * The frequency is interpolated exponentially, so the pitch rises or falls evenly to the ear.
+ * It's only updated when [update] is called, so the smoothness of the sweep depends on how often that happens.
+ *
+ * @param durationMs How long the sweep takes from [fromHz] to [toHz].
+ */
+class PwmSweep(
+    val pin: PwmPin,
+    val fromHz: Double,
+    val toHz: Double,
+    val durationMs: Long,
+) {
+    init {
+        require(fromHz > 0 && toHz > 0) { "Frequencies must be positive" }
+        require(durationMs > 0) { "Duration must be positive" }
+    }
+
+    var elapsedMs: Long = 0
+        private set
+
+    var isRunning: Boolean = false
+        private set
+
+    /**
+     * Starts the sweep from [fromHz] and enables the pin.
+     */
+    fun start() {
+        elapsedMs = 0
+        isRunning = true
+        pin.setFrequency(fromHz)
+        pin.enable()
+    }
+
+    /**
+     * Advances the sweep by [elapsedMs] milliseconds, disabling the pin once it's complete.
+     *
+     * @return `true` if the sweep is still running.
+     */
+    fun update(elapsedMs: Long): Boolean {
+        if (!isRunning) return false
+
+        this.elapsedMs += elapsedMs
+        if (this.elapsedMs >= durationMs) {
+            stop()
+            return false
+        }
+
+        val progress = this.elapsedMs.toDouble() / durationMs
+        pin.setFrequency(fromHz * (toHz / fromHz).pow(progress))
+        return true
+    }
+
+    /**
+     * Stops the sweep and disables the pin.
+     */
+    fun stop() {
+        isRunning = false
+        pin.disable()
+    }
+}
@@ -0,0 +1,111 @@
+package dev.thechilli.gpio4k.pwm
+
+import dev.thechilli.gpio4k.buzzer.MusicalNote
+import dev.thechilli.gpio4k.gpio.FrequencyCounter
+import dev.thechilli.gpio4k.gpio.GpioIOMode
+import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.utils.sleepMs
+import kotlin.math.abs
+import kotlin.math.pow
+import kotlin.math.roundToInt
+
+/**
+ * Sets the PWM frequency, keeping the duty cycle at the given [ratio] of the period.
+ *
+ * The duty cycle is updated first when shrinking the period, so it never exceeds the period in between,
+ * which backends like sysfs reject.
+ */
+fun PwmPin.setFrequency(frequencyHz: Double, ratio: Double = 0.5): PwmPin {
+    require(frequencyHz > 0) { "Frequency must be positive" }
+    require(ratio in 0.0..1.0) { "Ratio must be between 0.0 and 1.0" }
+
+    val periodNs = (1_000_000_000 / frequencyHz).toLong()
+    val dutyCycleNs = (periodNs * ratio).toLong()
+    if (periodNs < this.dutyCycleNs) {
+        setDutyCycleNs(dutyCycleNs)
+        setPeriodNs(periodNs)
+    } else {
+        setPeriodNs(periodNs)
+        setDutyCycleNs(dutyCycleNs)
+    }
+    return this
+}
+
+/**
+ * Starts playing a square wave at the frequency of the given [note], shifted by [octaveShift] octaves.
+ *
+ * The tone keeps playing until [stopNote] is called.
+ */
+fun PwmPin.playNote(note: MusicalNote, octaveShift: Int = 0): PwmPin {
+    setFrequency(note.frequencyHz.toDouble() * 2.0.pow(octaveShift))
+    enable()
+    return this
+}
+
+/**
+ * Stops the tone started by [playNote].
+ */
+fun PwmPin.stopNote(): PwmPin {
+    disable()
+    return this
+}
+
+/**
+ * Describes the current configuration of the PWM pin for logging, e.g.
+ * `SysFsPwmPin: enabled, period 2272727 ns, duty cycle 1136363 ns (50%), active high`.
+ */
+fun PwmPin.describe(): String = buildString {
+    append(this@describe::class.simpleName)
+    append(if (enabled) ": enabled" else ": disabled")
+    append(", period ").append(periodNs).append(" ns")
+    append(", duty cycle ").append(dutyCycleNs).append(" ns")
+    if (periodNs > 0) append(" (").append((ratio * 100).roundToInt()).append("%)")
+    append(if (activeLow) ", active low" else ", active high")
+}
+
+/**
+ * Checks that the [pwmPin] actually oscillates, by outputting [expectedHz] on it and measuring the [monitor] input
+ * with a [FrequencyCounter]. Useful as a power-on self-test of the buzzer path.
+ *
+ * Requires a physical jumper from the PWM pin to the monitor pin. The frequency has to be well below what the
+ * monitor backend can poll, see [FrequencyCounter], so a few hundred Hz at most for sysfs.
+ * The original period, duty cycle and enabled state of the PWM pin are restored afterward.
+ *
+ * @param tolerance Allowed relative deviation of the measured frequency, e.g. `0.1` for ±10%.
+ * @param windowMs Measurement window, longer windows reduce the error of counting whole edges.
+ * @param settleMs Time to wait after enabling the output before measuring.
+ * @return `true` if the measured frequency is within the tolerance.
+ */
+fun verifyPwmOutput(
+    pwmPin: PwmPin,
+    monitor: GpioPin,
+    expectedHz: Double,
+    tolerance: Double = 0.1,
+    windowMs: Int = 500,
+    settleMs: Int = 10,
+): Boolean {
+    require(tolerance >= 0) { "Tolerance must not be negative" }
+
+    val originalPeriodNs = pwmPin.periodNs
+    val originalDutyCycleNs = pwmPin.dutyCycleNs
+    val originalEnabled = pwmPin.enabled
+
+    monitor.setMode(GpioIOMode.INPUT)
+    try {
+        pwmPin.setFrequency(expectedHz)
+        pwmPin.enable()
+        sleepMs(settleMs)
+
+        val measuredHz = FrequencyCounter(monitor).measure(windowMs)
+        return abs(measuredHz - expectedHz) <= expectedHz * tolerance
+    } finally {
+        if (!originalEnabled) pwmPin.disable()
+        if (originalPeriodNs < pwmPin.dutyCycleNs) {
+            pwmPin.setDutyCycleNs(originalDutyCycleNs)
+            pwmPin.setPeriodNs(originalPeriodNs)
+        } else {
+            pwmPin.setPeriodNs(originalPeriodNs)
+            pwmPin.setDutyCycleNs(originalDutyCycleNs)
+        }
+    }
+}
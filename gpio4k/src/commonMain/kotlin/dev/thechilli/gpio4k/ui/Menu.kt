@@ -0,0 +1,81 @@
+package dev.thechilli.gpio4k.ui
+
+import dev.thechilli.gpio4k.lcd.CharacterDisplay
+import dev.thechilli.gpio4k.utils.Event
+
+/**
+ * A scrollable list of [items] rendered on a [CharacterDisplay], one item per row, with a marker on the selected one.
+ *
+ * The menu doesn't read any input itself: the caller maps its input device (keypad, rotary encoder, buttons)
+ * to [move] and [select], then calls [render].
+ *
+ * @param label Text shown for each item. Longer labels are cut to fit the display.
+ */
+class Menu<T>(
+    val items: List<T>,
+    private val label: (T) -> String = { it.toString() },
+) {
+    init {
+        require(items.isNotEmpty()) { "Menu must have at least one item" }
+    }
+
+    /**
+     * Invoked with the selected item when [select] is called.
+     */
+    val onSelect: Event<T> = Event()
+
+    var selectedIndex: Int = 0
+        private set
+
+    val selectedItem: T
+        get() = items[selectedIndex]
+
+    /**
+     * Index of the item shown on the first row.
+     */
+    var scrollOffset: Int = 0
+        private set
+
+    private var renderedLines: List<String>? = null
+
+    /**
+     * Moves the selection by [steps] items, negative moving up. Stops at the first and last item.
+     */
+    fun move(steps: Int) {
+        selectedIndex = (selectedIndex + steps).coerceIn(items.indices)
+    }
+
+    fun select() {
+        onSelect.invoke(selectedItem)
+    }
+
+    /**
+     * Forces the next [render] to redraw every row, e.g. after something else drew on the display.
+     */
+    fun invalidate() {
+        renderedLines = null
+    }
+
+    /**
+     * Draws the menu, scrolling to keep the selected item visible.
+     * Only rows that changed since the previous render are rewritten, to avoid flicker.
+     */
+    fun render(display: CharacterDisplay) {
+        val rows = display.rows
+        if (selectedIndex < scrollOffset) scrollOffset = selectedIndex
+        if (selectedIndex >= scrollOffset + rows) scrollOffset = selectedIndex - rows + 1
+
+        val lines = (0 until rows).map { row ->
+            val index = scrollOffset + row
+            if (index !in items.indices) return@map " ".repeat(display.columns)
+            val marker = if (index == selectedIndex) ">" else " "
+            (marker + label(items[index])).take(display.columns).padEnd(display.columns)
+        }
+
+        val previous = renderedLines
+        for ((row, line) in lines.withIndex()) {
+            if (previous == null || previous[row] != line) display.printLine(row, line)
+        }
+        renderedLines = lines
+    }
+}
@@ -0,0 +1,41 @@
+package dev.thechilli.gpio4k.utils
+
+/**
+ * A timer counting down from [durationMs] milliseconds.
+ *
+ * The countdown doesn't measure time on its own, the caller advances it by calling [tick] with the elapsed time,
+ * e.g. once per frame of the main loop.
+ */
+class Countdown(val durationMs: Long) {
+    init {
+        require(durationMs >= 0) { "Duration must not be negative" }
+    }
+
+    /**
+     * The time left until the countdown expires, in milliseconds. Never negative.
+     */
+    var remainingMs: Long = durationMs
+        private set
+
+    val isExpired: Boolean
+        get() = remainingMs == 0L
+
+    /**
+     * Advances the countdown by [elapsedMs] milliseconds.
+     * Overshooting the end clamps the remaining time to zero.
+     *
+     * @return `true` if the countdown is expired.
+     */
+    fun tick(elapsedMs: Long): Boolean {
+        require(elapsedMs >= 0) { "Elapsed time must not be negative" }
+        remainingMs = (remainingMs - elapsedMs).coerceAtLeast(0)
+        return isExpired
+    }
+
+    /**
+     * Restarts the countdown from [durationMs].
+     */
+    fun reset() {
+        remainingMs = durationMs
+    }
+}
@@ -0,0 +1,15 @@
+package dev.thechilli.gpio4k.utils
+
+/**
+ * Reads a whole UTF-8 text file.
+ *
+ * @throws IllegalStateException if the file can't be read, with the platform's I/O error as its cause where available.
+ */
+expect fun readTextFile(path: String): String
+
+/**
+ * Writes [text] to a file as UTF-8, replacing its contents.
+ *
+ * @throws IllegalStateException if the file can't be written, with the platform's I/O error as its cause where available.
+ */
+expect fun writeTextFile(path: String, text: String)
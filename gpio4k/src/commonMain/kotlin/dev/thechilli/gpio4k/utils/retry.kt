@@ -0,0 +1,24 @@
+package dev.thechilli.gpio4k.utils
+
+import dev.thechilli.gpio4k.gpio.GpioException
+
+/**
+ * Runs the [block], retrying up to [times] attempts in total as long as it fails with a retryable [GpioException].
+ * Waits [delayMs] milliseconds between attempts.
+ *
+ * Any other exception, or a retryable one on the last attempt, is rethrown.
+ */
+fun <T> retry(times: Int, delayMs: Int, block: () -> T): T {
+    require(times >= 1) { "Must try at least once" }
+
+    var attempt = 1
+    while (true) {
+        try {
+            return block()
+        } catch (e: GpioException) {
+            if (!e.isRetryable || attempt >= times) throw e
+        }
+        attempt++
+        sleepMs(delayMs)
+    }
+}
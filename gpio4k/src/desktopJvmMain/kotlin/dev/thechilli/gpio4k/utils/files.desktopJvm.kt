@@ -0,0 +1,20 @@
+package dev.thechilli.gpio4k.utils
+
+import java.io.File
+import java.io.IOException
+
+actual fun readTextFile(path: String): String {
+    try {
+        return File(path).readText()
+    } catch (e: IOException) {
+        throw IllegalStateException("Failed to read from $path", e)
+    }
+}
+
+actual fun writeTextFile(path: String, text: String) {
+    try {
+        File(path).writeText(text)
+    } catch (e: IOException) {
+        throw IllegalStateException("Failed to write to $path", e)
+    }
+}
@@ -0,0 +1,21 @@
+package dev.thechilli.gpio4k.buzzer
+
+import dev.thechilli.gpio4k.buzzer.Frequency.C4
+import dev.thechilli.gpio4k.buzzer.Frequency.E4
+import dev.thechilli.gpio4k.buzzer.Frequency.G4
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class ChordTest {
+    @Test
+    fun `Arpeggio should cycle notes and fill the chord duration`() {
+        val chord = Chord(listOf(C4, E4, G4), 100u)
+
+        val notes = chord.toArpeggio(30u)
+
+        assertEquals(
+            listOf(Note(C4, 30u), Note(E4, 30u), Note(G4, 30u), Note(C4, 10u)),
+            notes
+        )
+    }
+}
@@ -0,0 +1,74 @@
+package dev.thechilli.gpio4k.buzzer
+
+import dev.thechilli.gpio4k.pwm.MockedPwmPin
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertFalse
+
+class MelodyPlayerTest {
+    private val melody = Melody().apply {
+        addNote(MusicalNote.C4, 100u)
+        addRest(50u)
+        addNote(MusicalNote.E4, 150u)
+    }
+
+    @Test
+    fun `Player should report each note at its boundary`() {
+        val pin = MockedPwmPin("PWM0")
+        val player = MelodyPlayer(pin, melody)
+        val events = mutableListOf<Pair<Long, Note?>>()
+        player.onNote.subscribe { events.add(player.elapsedMs to it) }
+
+        player.start()
+        repeat(8) { player.update(40) }
+
+        assertEquals(
+            listOf(
+                0L to melody.notes[0],
+                120L to melody.notes[1],
+                160L to melody.notes[2],
+                320L to null,
+            ),
+            events,
+        )
+        assertFalse(player.isPlaying)
+        assertFalse(pin.enabled)
+    }
+
+    @Test
+    fun `Progress should follow the elapsed time`() {
+        val player = MelodyPlayer(MockedPwmPin("PWM0"), melody)
+
+        player.start()
+        player.update(150)
+
+        assertEquals(0.5, player.progress)
+    }
+
+    @Test
+    fun `Fading should ramp the duty cycle at both ends of a note`() {
+        val pin = MockedPwmPin("PWM0")
+        val player = MelodyPlayer(pin, melody)
+        player.setFadeMs(10)
+
+        player.start()
+        assertEquals(0.0, pin.ratio)
+        player.update(5)
+        assertEquals(0.25, pin.ratio, 0.001)
+        player.update(45)
+        assertEquals(0.5, pin.ratio, 0.001)
+        player.update(45)
+        assertEquals(0.25, pin.ratio, 0.001)
+    }
+
+    @Test
+    fun `Negative elapsed time should be rejected`() {
+        val player = MelodyPlayer(MockedPwmPin("PWM0"), melody)
+
+        player.start()
+        player.update(120)
+        assertFailsWith<IllegalArgumentException> { player.update(-50) }
+        assertEquals(120, player.elapsedMs)
+    }
+}
@@ -0,0 +1,127 @@
+package dev.thechilli.gpio4k.buzzer
+
+import java.io.File
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class MelodyTest {
+    private fun motif() = Melody().apply {
+        addNote(MusicalNote.C4, 100u)
+        addRest(50u)
+        addNote(MusicalNote.E4, 150u)
+    }
+
+    @Test
+    fun `Appending should concatenate notes and durations`() {
+        val melody = motif()
+
+        melody.append(motif())
+
+        assertEquals(6, melody.notes.size)
+        assertEquals(600u, melody.durationMs)
+        assertEquals(Note(0u, 50u), melody.notes[4])
+    }
+
+    @Test
+    fun `Scaling should stretch every duration`() {
+        val scaled = motif().scale(1.5)
+
+        assertEquals(listOf(150u, 75u, 225u), scaled.notes.map { it.durationMs })
+        assertEquals(450u, scaled.durationMs)
+    }
+
+    @Test
+    fun `Simple notation should parse notes, rests and dotted notes`() {
+        val melody = Melody.parseSimple("c4/4 e4/8. r/4 g#4/2", 120)
+
+        assertEquals(
+            listOf(
+                MusicalNote.C4.toNote(500u),
+                MusicalNote.E4.toNote(375u),
+                Note(0u, 500u),
+                MusicalNote.Gs4.toNote(1000u),
+            ),
+            melody.notes
+        )
+    }
+
+    @Test
+    fun `Simple notation should reject invalid tokens`() {
+        assertFailsWith<MelodyParseException> { Melody.parseSimple("c4/4 h4/4", 120) }
+        assertFailsWith<MelodyParseException> { Melody.parseSimple("c4", 120) }
+        assertFailsWith<MelodyParseException> { Melody.parseSimple("c4/0", 120) }
+    }
+
+    @Test
+    fun `Validation should report notes out of range but not rests`() {
+        val melody = Melody().apply {
+            addNote(MusicalNote.A3, 100u)
+            addRest(100u)
+            addNote(MusicalNote.C4, 100u)
+            addNote(MusicalNote.C7, 100u)
+        }
+
+        assertEquals(
+            listOf(0 to MusicalNote.A3.toNote(100u), 3 to MusicalNote.C7.toNote(100u)),
+            melody.validate(MusicalNote.C4, MusicalNote.B6),
+        )
+    }
+
+    @Test
+    fun `Text format should round-trip notes, rests and plain frequencies`() {
+        val melody = motif().apply {
+            addNote(MusicalNote.Fs5, 200u)
+            add(Note(1000u, 50u))
+        }
+
+        val text = melody.toText()
+
+        assertEquals("C4,100\n-,50\nE4,150\nF#5,200\n1000,50\n", text)
+        assertEquals(melody.notes, Melody.parseText(text).notes)
+    }
+
+    @Test
+    fun `Text parse errors should report the line`() {
+        val exception = assertFailsWith<MelodyParseException> {
+            Melody.parseText("# Fail jingle\nC4,100\n\nH4,100\n")
+        }
+
+        assertEquals(4, exception.line)
+    }
+
+    @Test
+    fun `Melody should round-trip through a file`() {
+        val melody = Melody().apply {
+            addNote(MusicalNote.C5, 100u)
+            addRest(50u)
+            addNote(MusicalNote.Gs5, 200u)
+        }
+        val file = File.createTempFile("jingle", ".txt")
+        try {
+            melody.saveTo(file.path)
+
+            assertEquals(melody.notes, Melody.loadFrom(file.path).notes)
+        } finally {
+            file.delete()
+        }
+    }
+
+    @Test
+    fun `Loading a file should report the line of a parse error`() {
+        val file = File.createTempFile("jingle", ".txt")
+        try {
+            file.writeText("# Broken jingle\nC5,100\nC5,long\n")
+
+            val exception = assertFailsWith<MelodyParseException> { Melody.loadFrom(file.path) }
+            assertEquals(3, exception.line)
+        } finally {
+            file.delete()
+        }
+    }
+
+    @Test
+    fun `Loading a missing file should fail`() {
+        assertFailsWith<IllegalStateException> { Melody.loadFrom("/nonexistent/jingle.txt") }
+    }
+}
@@ -0,0 +1,59 @@
+package dev.thechilli.gpio4k.buzzer
+
+import dev.thechilli.gpio4k.pwm.MockedPwmPin
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class MetronomeTest {
+    @Test
+    fun `Beats should fire every 500 ms at 120 BPM`() {
+        val metronome = Metronome(MockedPwmPin("PWM0"), 120)
+        metronome.setBeatsPerBar(3)
+        var timeMs = 0L
+        val beats = mutableListOf<Pair<Long, Int>>()
+        metronome.onBeat.subscribe { beats.add(timeMs to it) }
+
+        metronome.start()
+        for (step in listOf(200L, 250L, 100L, 450L, 300L, 250L)) {
+            timeMs += step
+            metronome.update(step)
+        }
+
+        assertEquals(listOf(0L to 0, 550L to 1, 1000L to 2, 1550L to 0), beats)
+    }
+
+    @Test
+    fun `Clicks should be short and accented on the downbeat`() {
+        val pin = MockedPwmPin("PWM0")
+        val metronome = Metronome(pin, 120)
+        metronome.setBeatsPerBar(2)
+
+        metronome.start()
+        assertTrue(pin.enabled)
+        assertEquals(1_000_000_000L / 1760, pin.periodNs)
+
+        metronome.update(40)
+        assertFalse(pin.enabled)
+
+        metronome.update(460)
+        assertTrue(pin.enabled)
+        assertEquals(1_000_000_000L / 880, pin.periodNs)
+
+        metronome.stop()
+        assertFalse(pin.enabled)
+        assertFalse(metronome.update(500))
+    }
+
+    @Test
+    fun `Clicks should be shorter than a beat`() {
+        val metronome = Metronome(MockedPwmPin("PWM0"), 120)
+
+        assertFailsWith<IllegalArgumentException> { metronome.setClickMs(500) }
+        metronome.setClickMs(100)
+        assertFailsWith<IllegalArgumentException> { metronome.setBpm(600) }
+        assertEquals(120, metronome.bpm)
+    }
+}
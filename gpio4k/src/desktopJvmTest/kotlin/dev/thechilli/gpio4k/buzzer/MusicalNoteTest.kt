@@ -0,0 +1,30 @@
+package dev.thechilli.gpio4k.buzzer
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertNull
+import kotlin.test.assertTrue
+
+class MusicalNoteTest {
+    @Test
+    fun `Notes should be ordered across octave boundaries`() {
+        assertTrue(MusicalNote.B3 < MusicalNote.C4)
+        assertTrue(MusicalNote.C4 < MusicalNote.Cs4)
+        assertEquals(MusicalNote.C4, MusicalNote.B3.next())
+        assertEquals(MusicalNote.B3, MusicalNote.C4.prev())
+    }
+
+    @Test
+    fun `Stepping should stop at the ends of the range`() {
+        assertNull(MusicalNote.C0.prev())
+        assertNull(MusicalNote.B8.next())
+    }
+
+    @Test
+    fun `Frequencies should match the Frequency constants`() {
+        assertEquals(Frequency.A4, MusicalNote.A4.frequencyHz)
+        assertEquals(Frequency.C3, MusicalNote.C3.frequencyHz)
+        assertEquals(Frequency.Cs4, MusicalNote.Cs4.frequencyHz)
+        assertEquals(Frequency.B5, MusicalNote.B5.frequencyHz)
+    }
+}
@@ -0,0 +1,52 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+/**
+ * Checks that every pin implementation applies [GpioPin.activeLow] the same way,
+ * so swapping backends doesn't change the logical values seen by the application.
+ */
+class ActiveLevelConformanceTest {
+    /**
+     * Creates an output pin under test, along with the mock holding its electrical state.
+     */
+    private val backends: Map<String, () -> Pair<GpioPin, MockedGpioPin>> = mapOf(
+        "mock" to {
+            val mock = MockedGpioPin("out")
+            mock to mock
+        },
+        "software open-drain" to {
+            val mock = MockedGpioPin("out")
+            val pin = SoftwareDriveGpioPin(mock)
+                .setDrive(GpioDriveMode.OPEN_DRAIN)
+                .setBias(GpioLineBias.PULL_UP)
+            pin to mock
+        },
+        "software open-source" to {
+            val mock = MockedGpioPin("out")
+            val pin = SoftwareDriveGpioPin(mock)
+                .setDrive(GpioDriveMode.OPEN_SOURCE)
+                .setBias(GpioLineBias.PULL_DOWN)
+            pin to mock
+        },
+    )
+
+    @Test
+    fun `Logical values should round-trip on every backend and active level`() {
+        for ((name, create) in backends) {
+            for (activeLow in listOf(false, true)) {
+                for (value in listOf(false, true)) {
+                    val (output, mock) = create()
+                    output.setMode(GpioIOMode.OUTPUT).setActiveLow(activeLow)
+                    val input = MockedGpioPin("in").apply { setActiveLow(activeLow) }
+
+                    output.write(value)
+                    input.externalState = mock.getFinalState()
+
+                    assertEquals(value, input.read(), "$name, activeLow = $activeLow")
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.pwm.MockedPwmPin
+import dev.thechilli.gpio4k.utils.closingScope
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertFalse
+
+class CleanupGuardTest {
+    @Test
+    fun `Guard should release outputs when the scope fails`() {
+        val solenoid = MockedGpioPin("solenoid")
+        val buzzer = MockedPwmPin("buzzer")
+
+        assertFailsWith<IllegalStateException> {
+            closingScope {
+                CleanupGuard(listOf(solenoid), listOf(buzzer)).autoClose()
+
+                solenoid.setMode(GpioIOMode.OUTPUT).setActiveLow(true)
+                solenoid.write(true)
+                buzzer.enable()
+                error("Main loop crashed")
+            }
+        }
+
+        assertEquals(GpioIOMode.INPUT, solenoid.mode)
+        assertFalse(solenoid.activeLow)
+        assertFalse(buzzer.enabled)
+    }
+
+    @Test
+    fun `Guard should reset the remaining pins after a failure`() {
+        val broken = object : GpioPin by MockedGpioPin("broken") {
+            override fun setMode(mode: GpioIOMode): GpioPin = throw GpioException("Pin is gone")
+        }
+        val led = MockedGpioPin("led").apply { setMode(GpioIOMode.OUTPUT) }
+
+        assertFailsWith<GpioException> {
+            CleanupGuard(listOf(broken, led)).close()
+        }
+        assertEquals(GpioIOMode.INPUT, led.mode)
+    }
+}
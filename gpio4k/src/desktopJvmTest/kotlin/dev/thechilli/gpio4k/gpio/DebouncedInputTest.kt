@@ -0,0 +1,29 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.gpio.DebouncedInput.Edge
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class DebouncedInputTest {
+    private var nowMs = -1L
+
+    /**
+     * A contact bouncing between 5 and 9 ms, then closed until 30 ms.
+     */
+    private val pin = object : GpioPin by MockedGpioPin("door") {
+        override fun read() = when (nowMs) {
+            in 5L..9L -> nowMs % 2 == 1L
+            in 10L..29L -> true
+            else -> false
+        }
+    }
+
+    @Test
+    fun `Edges should skip the bounces and report each settled change`() {
+        val input = DebouncedInput(pin, debounceMs = 3)
+
+        val edges = input.edges { ++nowMs }.take(2).toList()
+
+        assertEquals(listOf(Edge(true, 12), Edge(false, 33)), edges)
+    }
+}
@@ -0,0 +1,98 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+import kotlin.time.TimeSource
+
+class DiagnosticsTest {
+    @Test
+    fun `Disconnected pin should be detected as floating`() {
+        val pin = MockedGpioPin("encoder A").apply { setActiveLow(true) }
+
+        assertTrue(detectFloating(pin, settleUs = 0))
+        assertEquals(GpioLineBias.NONE, pin.bias)
+    }
+
+    @Test
+    fun `Driven pin should not be detected as floating`() {
+        val pin = MockedGpioPin("encoder A").apply {
+            setBias(GpioLineBias.PULL_UP)
+            externalState = false
+        }
+
+        assertFalse(detectFloating(pin, settleUs = 0))
+        assertEquals(GpioLineBias.PULL_UP, pin.bias)
+    }
+
+    /**
+     * An input connected to the [source] output, reading its electrical level.
+     */
+    private class WiredInput(val source: MockedGpioPin) : GpioPin by MockedGpioPin("in") {
+        override fun read() = source.getFinalState()!! != activeLow
+    }
+
+    @Test
+    fun `Bus loopback should fail both pins of a swapped pair`() {
+        val outputs = List(4) { MockedGpioPin("out$it") }
+        outputs[3].setActiveLow(true)
+        val inputs = listOf(0, 2, 1, 3).map { WiredInput(outputs[it]) }
+
+        assertEquals(listOf(true, false, false, true), busLoopbackTest(outputs, inputs, settleUs = 0))
+    }
+
+    @Test
+    fun `Loopback should pass on a jumpered pair`() {
+        val output = MockedGpioPin("out")
+
+        assertTrue(loopbackTest(output, WiredInput(output), settleUs = 0))
+    }
+
+    @Test
+    fun `Loopback should fail on an unconnected pair`() {
+        val input = MockedGpioPin("in").apply { setBias(GpioLineBias.PULL_DOWN) }
+
+        assertFalse(loopbackTest(MockedGpioPin("out"), input, settleUs = 0))
+    }
+
+    @Test
+    fun `Loopback should compare electrical levels when only one side is active-low`() {
+        val activeLowOutput = MockedGpioPin("out").apply { setActiveLow(true) }
+        assertTrue(loopbackTest(activeLowOutput, WiredInput(activeLowOutput), settleUs = 0))
+
+        val output = MockedGpioPin("out")
+        val activeLowInput = WiredInput(output).apply { setActiveLow(true) }
+        assertTrue(loopbackTest(output, activeLowInput, settleUs = 0))
+    }
+
+    /**
+     * An output whose level only reaches the [input] [lagMs] milliseconds after each write, like a slow line.
+     */
+    private class SlowLine(val lagMs: Long) {
+        private var level = false
+        private var previousLevel = false
+        private var changedAt = TimeSource.Monotonic.markNow()
+
+        val output = object : GpioPin by MockedGpioPin("out") {
+            override fun write(value: Boolean) {
+                previousLevel = level
+                level = value
+                changedAt = TimeSource.Monotonic.markNow()
+            }
+        }
+
+        val input = object : GpioPin by MockedGpioPin("in") {
+            override fun read() = if (changedAt.elapsedNow().inWholeMilliseconds >= lagMs) level else previousLevel
+        }
+    }
+
+    @Test
+    fun `Loopback should wait for the line to settle`() {
+        val unsettled = SlowLine(lagMs = 50)
+        assertFalse(loopbackTest(unsettled.output, unsettled.input, settleUs = 0))
+
+        val settled = SlowLine(lagMs = 50)
+        assertTrue(loopbackTest(settled.output, settled.input, settleUs = 100_000))
+    }
+}
@@ -0,0 +1,50 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.time.TimeSource
+
+class FrequencyCounterTest {
+    /**
+     * Drives the external state of the [pin] with a square wave of [frequencyHz], high for the first half
+     * of each period, updated right before every read.
+     */
+    private class SquareWave(val pin: MockedGpioPin, val frequencyHz: Int) : GpioPin by pin {
+        private val start = TimeSource.Monotonic.markNow()
+
+        override fun read(): Boolean {
+            val periodNs = 1_000_000_000L / frequencyHz
+            pin.externalState = start.elapsedNow().inWholeNanoseconds % periodNs < periodNs / 2
+            return pin.read()
+        }
+    }
+
+    @Test
+    fun `Counter should measure a square wave`() {
+        val counter = FrequencyCounter(SquareWave(MockedGpioPin("in"), 100))
+
+        // A 200 ms window holds 20 edges, give or take the one at each end
+        assertEquals(100.0, counter.measure(200), 10.0)
+    }
+
+    @Test
+    fun `Counter should measure an active-low input the same`() {
+        val pin = MockedGpioPin("in").apply { setActiveLow(true) }
+        val counter = FrequencyCounter(SquareWave(pin, 100))
+
+        assertEquals(100.0, counter.measure(200), 10.0)
+    }
+
+    @Test
+    fun `Counter should report zero for a constant level`() {
+        val pin = MockedGpioPin("in").apply { externalState = true }
+
+        assertEquals(0.0, FrequencyCounter(pin).measure(50))
+    }
+
+    @Test
+    fun `Counter should reject an empty window`() {
+        assertFailsWith<IllegalArgumentException> { FrequencyCounter(MockedGpioPin("in")).measure(0) }
+    }
+}
@@ -0,0 +1,219 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class GpioExtTest {
+    private fun outputPins(count: Int) = List(count) { i ->
+        MockedGpioPin("D$i").apply { setMode(GpioIOMode.OUTPUT) }
+    }
+
+    private fun List<MockedGpioPin>.levels() = map { it.internallyExpected!! }
+
+    /**
+     * An input returning the given [readings] in order, then repeating the last one.
+     */
+    private class NoisyPin(vararg val readings: Boolean) : GpioPin by MockedGpioPin("noisy") {
+        private var index = 0
+
+        override fun read() = readings[index.coerceAtMost(readings.lastIndex)].also { index++ }
+    }
+
+    @Test
+    fun `writeByteAsNibbles should write the high nibble first`() {
+        val pins = outputPins(4)
+        val nibbles = mutableListOf<List<Boolean>>()
+
+        pins.writeByteAsNibbles(0b1010_0011u) {
+            nibbles.add(pins.levels())
+        }
+
+        assertEquals(
+            listOf(
+                listOf(true, false, true, false),
+                listOf(false, false, true, true),
+            ),
+            nibbles
+        )
+    }
+
+    @Test
+    fun `writeAll should reject mismatched lengths`() {
+        val pins = outputPins(3)
+
+        assertFailsWith<IllegalArgumentException> {
+            pins.writeAll(listOf(true, false))
+        }
+    }
+
+    @Test
+    fun `writeAllBestEffort should continue past failures`() {
+        val pins = outputPins(3)
+        pins[1].setMode(GpioIOMode.INPUT)
+
+        val results = pins.writeAllBestEffort(listOf(true, true, false))
+
+        assertEquals(listOf(true, false, true), results.map { it.isSuccess })
+        assertEquals(true, pins[0].internallyExpected)
+        assertEquals(false, pins[2].internallyExpected)
+    }
+
+    @Test
+    fun `writeBits should put bit 0 on the first pin`() {
+        val pins = outputPins(5)
+
+        pins.writeBits(0b10110u)
+
+        assertEquals(listOf(false, true, true, false, true), pins.levels())
+    }
+
+    @Test
+    fun `writeBits should reject values wider than the bus`() {
+        val pins = outputPins(5)
+
+        assertFailsWith<IllegalArgumentException> { pins.writeBits(0b100000u) }
+    }
+
+    @Test
+    fun `readBits should put the first pin in bit 0`() {
+        val pins = listOf(true, true, false, false, true).mapIndexed { i, level ->
+            MockedGpioPin("D$i").apply { externalState = level }
+        }
+
+        assertEquals(0b10011u, pins.readBits())
+    }
+
+    @Test
+    fun `readStable should return the majority of each pin`() {
+        val pins = listOf(
+            NoisyPin(true, false, true),
+            NoisyPin(false, false, true),
+            NoisyPin(true, true, true),
+        )
+
+        assertEquals(listOf(true, false, true), pins.readStable(samples = 3))
+    }
+
+    @Test
+    fun `readStable should reject an even sample count`() {
+        assertFailsWith<IllegalArgumentException> {
+            listOf(NoisyPin(true)).readStable(samples = 4)
+        }
+    }
+
+    @Test
+    fun `writeString should pulse once after each byte`() {
+        val pins = outputPins(8)
+        val events = mutableListOf<String>()
+
+        pins.writeString("PiL") {
+            val byte = pins.levels().fold(0) { acc, level -> acc shl 1 or if (level) 1 else 0 }
+            events.add("pulse ${byte.toChar()}")
+        }
+
+        assertEquals(listOf("pulse P", "pulse i", "pulse L"), events)
+    }
+
+    @Test
+    fun `withOutput should restore the previous mode, even on failure`() {
+        val pin = MockedGpioPin("DB7")
+
+        pin.withOutput { it.write(true) }
+        assertEquals(GpioIOMode.INPUT, pin.mode)
+        assertEquals(null, pin.internallyExpected)
+
+        assertFailsWith<IllegalStateException> {
+            pin.withOutput { error("Transfer failed") }
+        }
+        assertEquals(GpioIOMode.INPUT, pin.mode)
+    }
+
+    @Test
+    fun `withInput should keep the new mode when not restoring`() {
+        val pin = MockedGpioPin("DB7").apply { setMode(GpioIOMode.OUTPUT) }
+        pin.externalState = true
+
+        val value = pin.withInput(restore = false) { it.read() }
+
+        assertEquals(true, value)
+        assertEquals(GpioIOMode.INPUT, pin.mode)
+    }
+
+    @Test
+    fun `describe should include the configuration and the input level`() {
+        val input = MockedGpioPin("button").apply {
+            setActiveLow(true)
+            setBias(GpioLineBias.PULL_UP)
+            externalState = false
+        }
+        val output = outputPins(1).single()
+
+        assertEquals(
+            "MockedGpioPin: INPUT, active low, bias PULL_UP, drive PUSH_PULL, reads true",
+            input.describe(),
+        )
+        assertEquals("MockedGpioPin: OUTPUT, active high, bias NONE, drive PUSH_PULL", output.describe())
+    }
+
+    @Test
+    fun `MSB-first bits should put the first pin in the highest bit`() {
+        val pins = listOf(true, false, false, false).mapIndexed { i, level ->
+            MockedGpioPin("R$i").apply { externalState = level }
+        }
+
+        assertEquals(0b1000u, pins.readBitsMsbFirst())
+        assertEquals(0b0001u, pins.readBits())
+    }
+
+    @Test
+    fun `writeBitsMsbFirst should mirror writeBits`() {
+        val msbFirst = outputPins(4)
+        val lsbFirst = outputPins(4)
+
+        msbFirst.writeBitsMsbFirst(0b1100u)
+        lsbFirst.writeBits(0b1100u)
+
+        assertEquals(listOf(true, true, false, false), msbFirst.levels())
+        assertEquals(listOf(false, false, true, true), lsbFirst.levels())
+        assertFailsWith<IllegalArgumentException> { msbFirst.writeBitsMsbFirst(0b10000u) }
+    }
+
+    @Test
+    fun `readBoth should return the electrical level and the logical value`() {
+        val button = MockedGpioPin("button").apply {
+            setActiveLow(true)
+            setBias(GpioLineBias.PULL_UP)
+        }
+
+        assertEquals(true to false, button.readBoth())
+
+        button.externalState = false
+        assertEquals(false to true, button.readBoth())
+    }
+
+    @Test
+    fun `setBiasVerified should accept a bias the hardware applied`() {
+        val pin = MockedGpioPin("button")
+
+        pin.setBiasVerified(GpioLineBias.PULL_UP)
+        assertEquals(GpioLineBias.PULL_UP, pin.bias)
+    }
+
+    @Test
+    fun `setBiasVerified should reject a bias the hardware ignored`() {
+        val pin = MockedGpioPin("button").apply { appliedBias = GpioLineBias.NONE }
+
+        assertFailsWith<GpioException> { pin.setBiasVerified(GpioLineBias.PULL_UP) }
+    }
+
+    @Test
+    fun `setBiasVerified should fall back to setBias on backends without readback`() {
+        val pin = object : GpioPin by MockedGpioPin("button") {
+            override fun readBias(): GpioLineBias? = null
+        }
+
+        pin.setBiasVerified(GpioLineBias.PULL_UP)
+        assertEquals(GpioLineBias.PULL_UP, pin.bias)
+    }
+}
@@ -0,0 +1,32 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+
+class GpioPinTest {
+    private fun assertDefaults(pin: GpioPin) {
+        assertEquals(GpioIOMode.DEFAULT, pin.mode)
+        assertEquals(GpioLineBias.DEFAULT, pin.bias)
+        assertEquals(GpioDriveMode.DEFAULT, pin.drive)
+        assertFalse(pin.activeLow)
+    }
+
+    @Test
+    fun `New pins should report the default configuration`() {
+        assertDefaults(MockedGpioPin("fresh"))
+        assertDefaults(SoftwareDriveGpioPin(MockedGpioPin("fresh")))
+    }
+
+    @Test
+    fun `reset should restore the default configuration`() {
+        val pin = MockedGpioPin("used")
+            .setMode(GpioIOMode.OUTPUT)
+            .setActiveLow(true)
+            .setBias(GpioLineBias.PULL_UP)
+            .setDrive(GpioDriveMode.OPEN_DRAIN)
+
+        pin.reset()
+        assertDefaults(pin)
+    }
+}
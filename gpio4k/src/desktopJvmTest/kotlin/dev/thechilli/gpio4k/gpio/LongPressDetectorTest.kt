@@ -0,0 +1,43 @@
+package dev.thechilli.gpio4k.gpio
+
+import dev.thechilli.gpio4k.gpio.LongPressDetector.PressEvent
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class LongPressDetectorTest {
+    private val pin = MockedGpioPin("button").apply { externalState = false }
+    private val detector = LongPressDetector(DebouncedInput(pin, 10), longPressMs = 1000, repeatMs = 200)
+
+    /**
+     * Polls the detector every 10 ms in the given time range, collecting the reported events.
+     */
+    private fun pollRange(range: LongProgression): List<Pair<Long, PressEvent>> =
+        range.mapNotNull { now -> detector.poll(now)?.let { now to it } }
+
+    @Test
+    fun `Quick press should report a short press on release`() {
+        pin.externalState = true
+        assertEquals(emptyList(), pollRange(0L..300L step 10))
+        pin.externalState = false
+        assertEquals(listOf(320L to PressEvent.SHORT), pollRange(310L..400L step 10))
+    }
+
+    @Test
+    fun `Holding should report a long press and then repeats`() {
+        pin.externalState = true
+
+        val events = pollRange(0L..1500L step 10)
+
+        assertEquals(
+            listOf(
+                1010L to PressEvent.LONG,
+                1210L to PressEvent.REPEAT,
+                1410L to PressEvent.REPEAT,
+            ),
+            events
+        )
+
+        pin.externalState = false
+        assertEquals(emptyList(), pollRange(1510L..1600L step 10))
+    }
+}
@@ -0,0 +1,36 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class OpenDrainLineTest {
+    @Test
+    fun `Line should only drive low and float when released`() {
+        val pin = MockedGpioPin("SDA")
+        val line = OpenDrainLine(pin)
+
+        assertEquals(GpioIOMode.INPUT, pin.mode)
+
+        line.pullLow()
+        assertEquals(GpioIOMode.OUTPUT, pin.mode)
+        assertEquals(false, pin.internallyExpected)
+        assertFalse(line.read())
+
+        line.release()
+        assertEquals(GpioIOMode.INPUT, pin.mode)
+        pin.externalState = true
+        assertTrue(line.read())
+    }
+
+    @Test
+    fun `Released line should read another device pulling it low`() {
+        val pin = MockedGpioPin("SDA")
+        val line = OpenDrainLine(pin)
+
+        pin.externalState = false
+
+        assertFalse(line.read())
+    }
+}
@@ -0,0 +1,35 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class SoftwareDriveGpioPinTest {
+    /**
+     * Mimics a backend that forgets the bias whenever the line changes direction.
+     */
+    private class BiasDroppingPin(val pin: MockedGpioPin) : GpioPin by pin {
+        override fun setMode(mode: GpioIOMode): GpioPin {
+            pin.setMode(mode)
+            pin.setBias(GpioLineBias.NONE)
+            return this
+        }
+    }
+
+    @Test
+    fun `Bias should persist when an open-drain output floats`() {
+        val mock = MockedGpioPin("sda")
+        val pin = SoftwareDriveGpioPin(BiasDroppingPin(mock))
+            .setMode(GpioIOMode.OUTPUT)
+            .setDrive(GpioDriveMode.OPEN_DRAIN)
+            .setBias(GpioLineBias.PULL_UP)
+
+        pin.write(false)
+        assertEquals(GpioIOMode.OUTPUT, mock.mode)
+        assertEquals(false, mock.getFinalState())
+
+        pin.write(true)
+        assertEquals(GpioIOMode.INPUT, mock.mode)
+        assertEquals(GpioLineBias.PULL_UP, mock.bias)
+        assertEquals(true, mock.getFinalState())
+    }
+}
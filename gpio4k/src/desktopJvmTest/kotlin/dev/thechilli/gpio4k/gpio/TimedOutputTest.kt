@@ -0,0 +1,64 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class TimedOutputTest {
+    @Test
+    fun `Output should be released exactly at expiry`() {
+        val pin = MockedGpioPin("solenoid")
+        val output = TimedOutput(pin)
+
+        output.activate(100)
+        assertTrue(output.tick(99))
+        assertEquals(true, pin.internallyExpected)
+
+        assertFalse(output.tick(1))
+        assertFalse(output.isActive)
+        assertEquals(false, pin.internallyExpected)
+    }
+
+    @Test
+    fun `Activating again should restart the window`() {
+        val pin = MockedGpioPin("solenoid")
+        val output = TimedOutput(pin)
+
+        output.activate(100)
+        output.tick(80)
+        output.activate(100)
+
+        assertTrue(output.tick(80))
+        assertEquals(20, output.remainingMs)
+        assertFalse(output.tick(20))
+    }
+
+    @Test
+    fun `Closing should release an active output`() {
+        val pin = MockedGpioPin("solenoid")
+        val output = TimedOutput(pin)
+
+        output.activate(100)
+        output.close()
+
+        assertFalse(output.isActive)
+        assertEquals(false, pin.internallyExpected)
+    }
+
+    @Test
+    fun `Active-low output should end electrically high`() {
+        val pin = MockedGpioPin("relay").apply { setActiveLow(true) }
+        val output = TimedOutput(pin)
+
+        output.activate(100)
+        assertEquals(false, pin.getFinalState())
+
+        output.tick(100)
+        assertEquals(true, pin.getFinalState())
+
+        output.activate(100)
+        output.close()
+        assertEquals(true, pin.getFinalState())
+    }
+}
@@ -0,0 +1,81 @@
+package dev.thechilli.gpio4k.i2c
+
+import dev.thechilli.gpio4k.gpio.GpioException
+import dev.thechilli.gpio4k.gpio.GpioIOMode
+import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.gpio.MockedGpioPin
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class I2cBitbangTest {
+    /**
+     * Decodes the bus like a logic analyzer: `S` for a start, `P` for a stop, and each clocked bit as `0` or `1`.
+     * The simulated device answers every read of SDA with [deviceSda].
+     */
+    private class Bus(var deviceSda: Boolean = false) {
+        val trace = StringBuilder()
+        private var sdaHigh = true
+        private var sclHigh = true
+        private var conditionWhileHigh = false
+
+        val sda = Line("SDA") { deviceSda }
+        val scl = Line("SCL") { true }
+
+        inner class Line(name: String, private val readValue: () -> Boolean) : GpioPin by MockedGpioPin(name) {
+            private var driven = false
+
+            override fun setMode(mode: GpioIOMode): GpioPin {
+                driven = mode == GpioIOMode.OUTPUT
+                update()
+                return this
+            }
+
+            override fun write(value: Boolean) = update()
+
+            override fun read() = readValue()
+
+            // Pulled up unless driven; lines are only ever driven low
+            val high get() = !driven
+        }
+
+        private fun update() {
+            val sdaNow = sda.high
+            val sclNow = scl.high
+
+            if (sclHigh && sclNow && sdaHigh != sdaNow) {
+                trace.append(if (sdaNow) "P" else "S")
+                conditionWhileHigh = true
+            }
+            if (sclHigh && !sclNow) {
+                if (!conditionWhileHigh) trace.append(if (sdaNow) "1" else "0")
+            }
+            if (!sclHigh && sclNow) conditionWhileHigh = false
+
+            sdaHigh = sdaNow
+            sclHigh = sclNow
+        }
+    }
+
+    @Test
+    fun `Write should send start, address, data and stop`() {
+        val bus = Bus()
+        val i2c = I2cBitbang(bus.sda, bus.scl, halfPeriodUs = 0)
+
+        i2c.write(0x50u, ubyteArrayOf(0x0Fu))
+
+        // Address 0x50 with the write bit, the released ACK bit, the data byte, its ACK bit
+        assertEquals("S" + "10100000" + "1" + "00001111" + "1" + "P", bus.trace.toString())
+    }
+
+    @Test
+    fun `Write should fail and stop when the address isn't acknowledged`() {
+        val bus = Bus(deviceSda = true)
+        val i2c = I2cBitbang(bus.sda, bus.scl, halfPeriodUs = 0)
+
+        assertFailsWith<GpioException> {
+            i2c.write(0x27u, ubyteArrayOf(0x00u))
+        }
+        assertEquals("S" + "01001110" + "1" + "P", bus.trace.toString())
+    }
+}
@@ -0,0 +1,24 @@
+package dev.thechilli.gpio4k.lcd
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class CharacterDisplayTest {
+    private fun MockHD44780CharacterDisplay.readLine(row: Int): String = (0 until columns).map { column ->
+        setCursor(row, column)
+        readChar()
+    }.joinToString("")
+
+    @Test
+    fun `clearLine should only clear the given line`() {
+        val display = MockHD44780CharacterDisplay(4, 20)
+        display.printLine(0, "First line")
+        display.printLine(1, "Second line")
+
+        display.clearLine(1)
+
+        assertEquals(display.getLineOffsets[1], display.currentAddress)
+        assertEquals("First line".padEnd(20), display.readLine(0))
+        assertEquals(" ".repeat(20), display.readLine(1))
+    }
+}
@@ -0,0 +1,71 @@
+package dev.thechilli.gpio4k.lcd
+
+import dev.thechilli.gpio4k.gpio.MockedGpioPin
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class DirectDOGM204DisplayTest {
+    private class RecordingDisplay : DirectDOGM204Display(
+        MockedGpioPin("RST"),
+        MockedGpioPin("RS"),
+        null,
+        MockedGpioPin("E"),
+        List(8) { MockedGpioPin("D${7 - it}") },
+        4,
+        20,
+    ) {
+        val commands = mutableListOf<UByte>()
+
+        override fun writeData(rs: Boolean, data: UByte, reBit: Boolean?, isBit: Boolean?) {
+            commands.add(data)
+        }
+    }
+
+    @Test
+    fun `Changing the font width should keep the line count bit`() {
+        val display = RecordingDisplay()
+        display.extendedFunctionSet(widerFont = false, cursorInvert = false, nw = true)
+
+        display.setFontWidth(true)
+        assertEquals<UByte>(0b0000_1101u, display.commands.last())
+
+        display.setFontWidth(false)
+        assertEquals<UByte>(0b0000_1001u, display.commands.last())
+    }
+
+    @Test
+    fun `Clearing should re-send the tracked display control`() {
+        val display = RecordingDisplay()
+        display.displayControl(displayOn = true, cursorOn = false, cursorBlink = true)
+
+        display.clearDisplay()
+        assertEquals<List<UByte>>(listOf(0x01u, 0b0000_1101u), display.commands.takeLast(2))
+
+        display.restoreDisplayControlOnClear = false
+        display.clearDisplay()
+        assertEquals<UByte>(0x01u, display.commands.last())
+    }
+
+    @Test
+    fun `Temperature compensation should set the nearest coefficient`() {
+        val display = RecordingDisplay()
+
+        val coefficient = display.applyTemperatureCompensation(10f, slopePercentPerDegree = -0.12f)
+
+        assertEquals(DOGM204Display.DOGM204TemperatureCoefficient.TC_0_10, coefficient)
+        assertEquals<List<UByte>>(listOf(0b0111_0110u, 0b001u), display.commands)
+    }
+
+    @Test
+    fun `Temperature compensation should nudge the contrast when asked`() {
+        val display = RecordingDisplay()
+
+        // 26/31 at 25 °C, raised by 0.1 at 15 °C to 29/31
+        display.applyTemperatureCompensation(15f, contrastPerDegree = 0.01f)
+
+        assertEquals<List<UByte>>(
+            listOf(0b0111_0110u, 0b010u, 0b0101_0111u, 0b0111_1101u),
+            display.commands,
+        )
+    }
+}
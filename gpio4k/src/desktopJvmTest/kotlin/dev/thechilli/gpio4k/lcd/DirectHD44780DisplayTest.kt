@@ -0,0 +1,117 @@
+package dev.thechilli.gpio4k.lcd
+
+import dev.thechilli.gpio4k.gpio.GpioIOMode
+import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.gpio.MockedGpioPin
+import dev.thechilli.gpio4k.gpio.GpioException
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class DirectHD44780DisplayTest {
+    private class CountingPin(val pin: MockedGpioPin) : GpioPin by pin {
+        var modeChanges = 0
+
+        override fun setMode(mode: GpioIOMode): GpioPin {
+            modeChanges++
+            return pin.setMode(mode)
+        }
+    }
+
+    /**
+     * An enable pin recording the levels of the [dataPins] on every falling edge, when the controller latches them.
+     * The first data pin is the most significant one.
+     */
+    private class LatchRecorder(val dataPins: List<MockedGpioPin>) : GpioPin by MockedGpioPin("E") {
+        val latched = mutableListOf<Int>()
+        private var high = false
+
+        override fun write(value: Boolean) {
+            if (high && !value) {
+                latched.add(dataPins.fold(0) { acc, pin -> acc shl 1 or if (pin.internallyExpected!!) 1 else 0 })
+            }
+            high = value
+        }
+    }
+
+    /**
+     * An enable pin putting the next of the [values] on the [dataPins] on every rising edge, like a controller
+     * answering a read. The first data pin is the most significant one.
+     */
+    private class ReadResponder(val dataPins: List<MockedGpioPin>, vararg val values: Int) : GpioPin by MockedGpioPin("E") {
+        private var next = 0
+
+        override fun write(value: Boolean) {
+            if (!value) return
+            val response = values[next++]
+            for ((i, pin) in dataPins.withIndex()) {
+                pin.externalState = response shr (dataPins.size - 1 - i) and 1 == 1
+            }
+        }
+    }
+
+    private fun mockPin(name: String) = MockedGpioPin(name)
+
+    @Test
+    fun `8-bit and 4-bit initialization should send the same commands`() {
+        val pins8 = List(8) { mockPin("D${7 - it}") }
+        val enable8 = LatchRecorder(pins8)
+        DirectHD44780Display(mockPin("RS"), null, enable8, pins8, 2, 16).initialize()
+
+        val pins4 = List(4) { mockPin("D${7 - it}") }
+        val enable4 = LatchRecorder(pins4)
+        DirectHD44780Display(mockPin("RS"), null, enable4, pins4, 2, 16).initialize()
+
+        assertEquals(listOf(0x30, 0x30, 0x30), enable8.latched.take(3))
+        assertEquals(listOf(0x3, 0x3, 0x3, 0x2), enable4.latched.take(4))
+
+        val commands8 = enable8.latched.drop(3)
+        val commands4 = enable4.latched.drop(4).chunked(2) { (high, low) -> high shl 4 or low }
+        // Function set, differing only in the data length bit
+        assertEquals(0b0011_1000, commands8.first())
+        assertEquals(0b0010_1000, commands4.first())
+        assertEquals(commands8.drop(1), commands4.drop(1))
+    }
+
+    @Test
+    fun `Data pins should only be switched to output once`() {
+        val dataPins = List(8) { CountingPin(mockPin("D$it")) }
+        val display = DirectHD44780Display(mockPin("RS"), null, mockPin("E"), dataPins, 2, 16)
+
+        display.writeData(true, 0x41u)
+        display.writeData(true, 0x42u)
+        display.writeData(false, 0x01u)
+
+        assertEquals(List(8) { 1 }, dataPins.map { it.modeChanges })
+    }
+
+    @Test
+    fun `Checked initialization should fail when the data bus only reads high`() {
+        // Nothing drives the bus, so the pull-ups make every bit, including the busy flag, read high
+        val dataPins = List(8) { mockPin("D${7 - it}").apply { externalState = true } }
+        val display = DirectHD44780Display(mockPin("RS"), mockPin("RW"), mockPin("E"), dataPins, 2, 16)
+
+        assertFailsWith<GpioException> { display.initializeChecked() }
+    }
+
+    @Test
+    fun `Checked initialization should pass on a responding controller`() {
+        MockHD44780CharacterDisplay(2, 16).initializeChecked()
+    }
+
+    @Test
+    fun `4-bit reads should combine two nibbles, the high one first`() {
+        val dataPins = List(4) { mockPin("D${7 - it}") }
+        val display = DirectHD44780Display(mockPin("RS"), mockPin("RW"), ReadResponder(dataPins, 0x5, 0xA), dataPins, 2, 16)
+
+        assertEquals<UByte>(0x5Au, display.readData(false))
+    }
+
+    @Test
+    fun `Checked initialization should report a stuck 4-bit bus instead of crashing`() {
+        val dataPins = List(4) { mockPin("D${7 - it}").apply { externalState = true } }
+        val display = DirectHD44780Display(mockPin("RS"), mockPin("RW"), mockPin("E"), dataPins, 2, 16)
+
+        assertFailsWith<GpioException> { display.initializeChecked() }
+    }
+}
@@ -0,0 +1,28 @@
+package dev.thechilli.gpio4k.pwm
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class PwmGroupTest {
+    private val pins = listOf("R", "G", "B").map { MockedPwmPin(it) }
+    private val group = PwmGroup(pins)
+
+    @Test
+    fun `Each channel should receive its own duty ratio`() {
+        group.setFrequencyHz(1000.0)
+        group.setDuties(listOf(1.0, 0.5, 0.0))
+
+        assertEquals(listOf(1_000_000L, 1_000_000L, 1_000_000L), pins.map { it.periodNs })
+        assertEquals(listOf(1_000_000L, 500_000L, 0L), pins.map { it.dutyCycleNs })
+
+        group.setFrequencyHz(2000.0)
+        assertEquals(listOf(500_000L, 250_000L, 0L), pins.map { it.dutyCycleNs })
+    }
+
+    @Test
+    fun `Invalid duties should be rejected`() {
+        assertFailsWith<IllegalArgumentException> { group.setDuties(listOf(0.5, 0.5)) }
+        assertFailsWith<IllegalArgumentException> { group.setDuties(listOf(0.5, 1.5, 0.5)) }
+    }
+}
@@ -0,0 +1,88 @@
+package dev.thechilli.gpio4k.pwm
+
+import dev.thechilli.gpio4k.buzzer.MusicalNote
+import dev.thechilli.gpio4k.gpio.GpioLineBias
+import dev.thechilli.gpio4k.gpio.GpioPin
+import dev.thechilli.gpio4k.gpio.MockedGpioPin
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+import kotlin.time.Duration
+import kotlin.time.Duration.Companion.microseconds
+import kotlin.time.Duration.Companion.milliseconds
+import kotlin.time.TimeSource
+
+class PwmPinTest {
+    /**
+     * An input jumpered to [pwm], following its square wave in real time.
+     */
+    private class JumperedInput(val pwm: PwmPin) : GpioPin by MockedGpioPin("monitor") {
+        private val start = TimeSource.Monotonic.markNow()
+
+        override fun read(): Boolean {
+            if (!pwm.enabled || pwm.periodNs == 0L) return false
+            return start.elapsedNow().inWholeNanoseconds % pwm.periodNs < pwm.dutyCycleNs
+        }
+    }
+
+    @Test
+    fun `Duration accessors should match the nanosecond ones`() {
+        val pin = MockedPwmPin("PWM0")
+
+        pin.setPeriod(2.milliseconds)
+        pin.setDutyCycle(500.microseconds)
+
+        assertEquals(2_000_000, pin.periodNs)
+        assertEquals(500_000, pin.dutyCycleNs)
+        assertEquals(2.milliseconds, pin.period)
+        assertEquals(500.microseconds, pin.dutyCycle)
+    }
+
+    @Test
+    fun `Duration setters should reject invalid durations`() {
+        val pin = MockedPwmPin("PWM0")
+
+        assertFailsWith<IllegalArgumentException> { pin.setPeriod(Duration.INFINITE) }
+        assertFailsWith<IllegalArgumentException> { pin.setDutyCycle((-1).milliseconds) }
+    }
+
+    @Test
+    fun `playNote should set the period of the note and enable the pin`() {
+        val pin = MockedPwmPin("PWM0")
+
+        pin.playNote(MusicalNote.A4)
+        assertEquals(1_000_000_000L / 440, pin.periodNs)
+        assertEquals(pin.periodNs / 2, pin.dutyCycleNs)
+        assertTrue(pin.enabled)
+
+        pin.playNote(MusicalNote.A4, octaveShift = 1)
+        assertEquals(1_000_000_000L / 880, pin.periodNs)
+
+        pin.stopNote()
+        assertFalse(pin.enabled)
+    }
+
+    @Test
+    fun `describe should include the signal configuration`() {
+        val pin = MockedPwmPin("PWM0").playNote(MusicalNote.A4)
+
+        assertEquals(
+            "MockedPwmPin: enabled, period 2272727 ns, duty cycle 1136363 ns (50%), active high",
+            pin.describe(),
+        )
+    }
+
+    @Test
+    fun `verifyPwmOutput should measure the frequency through the monitor`() {
+        val pin = MockedPwmPin("PWM0").setPeriodNs(1_000_000).setDutyCycleNs(250_000)
+
+        assertTrue(verifyPwmOutput(pin, JumperedInput(pin), 50.0, windowMs = 200))
+        assertEquals(1_000_000, pin.periodNs)
+        assertEquals(250_000, pin.dutyCycleNs)
+        assertFalse(pin.enabled)
+
+        assertFalse(verifyPwmOutput(pin, MockedGpioPin("unconnected").apply { setBias(GpioLineBias.PULL_DOWN) }, 50.0, windowMs = 200))
+    }
+}
@@ -0,0 +1,38 @@
+package dev.thechilli.gpio4k.ui
+
+import dev.thechilli.gpio4k.lcd.MockHD44780CharacterDisplay
+import kotlin.test.Test
+import kotlin.test.assertEquals
+
+class MenuTest {
+    private fun MockHD44780CharacterDisplay.readLine(row: Int): String = (0 until columns).map { column ->
+        setCursor(row, column)
+        readChar()
+    }.joinToString("")
+
+    @Test
+    fun `Menu should scroll to keep the selection visible`() {
+        val display = MockHD44780CharacterDisplay(2, 16)
+        val menu = Menu(listOf("Unlock time", "Password", "Contrast"))
+
+        menu.move(2)
+        menu.render(display)
+
+        assertEquals(1, menu.scrollOffset)
+        assertEquals(" Password".padEnd(16), display.readLine(0))
+        assertEquals(">Contrast".padEnd(16), display.readLine(1))
+    }
+
+    @Test
+    fun `Selecting should report the selected item`() {
+        val menu = Menu(listOf(5, 10, 30)) { "$it s" }
+        var selected: Int? = null
+        menu.onSelect.subscribe { selected = it }
+
+        menu.move(1)
+        menu.move(5)
+        menu.select()
+
+        assertEquals(30, selected)
+    }
+}
@@ -0,0 +1,39 @@
+package dev.thechilli.gpio4k.utils
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class CountdownTest {
+    @Test
+    fun `Countdown should expire exactly at the boundary`() {
+        val countdown = Countdown(1000)
+
+        assertFalse(countdown.tick(999))
+        assertEquals(1, countdown.remainingMs)
+        assertTrue(countdown.tick(1))
+        assertEquals(0, countdown.remainingMs)
+    }
+
+    @Test
+    fun `Countdown should clamp on overshoot`() {
+        val countdown = Countdown(100)
+
+        assertTrue(countdown.tick(250))
+        assertEquals(0, countdown.remainingMs)
+        assertTrue(countdown.tick(10))
+        assertEquals(0, countdown.remainingMs)
+    }
+
+    @Test
+    fun `Countdown should restart after reset`() {
+        val countdown = Countdown(100)
+        countdown.tick(100)
+
+        countdown.reset()
+
+        assertFalse(countdown.isExpired)
+        assertEquals(100, countdown.remainingMs)
+    }
+}
@@ -0,0 +1,67 @@
+package dev.thechilli.gpio4k.utils
+
+import dev.thechilli.gpio4k.gpio.GpioException
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class RetryTest {
+    @Test
+    fun `Retry should repeat retryable failures`() {
+        var attempts = 0
+
+        val result = retry(3, 0) {
+            attempts++
+            if (attempts < 3) throw GpioException("Busy", isRetryable = true)
+            "done"
+        }
+
+        assertEquals("done", result)
+        assertEquals(3, attempts)
+    }
+
+    @Test
+    fun `Retry should not repeat non-retryable failures`() {
+        var attempts = 0
+
+        assertFailsWith<GpioException> {
+            retry(3, 0) {
+                attempts++
+                throw GpioException("Not writable")
+            }
+        }
+        assertEquals(1, attempts)
+    }
+
+    @Test
+    fun `Retry should give up after the last attempt`() {
+        var attempts = 0
+
+        assertFailsWith<GpioException> {
+            retry(2, 0) {
+                attempts++
+                throw GpioException("Busy", isRetryable = true)
+            }
+        }
+        assertEquals(2, attempts)
+    }
+
+    @Test
+    fun `Retry should repeat timeouts but not parse failures`() {
+        var attempts = 0
+        retry(2, 0) {
+            attempts++
+            if (attempts < 2) throw GpioException.Timeout("Line stuck low")
+        }
+        assertEquals(2, attempts)
+
+        attempts = 0
+        assertFailsWith<GpioException.ParseFailure> {
+            retry(2, 0) {
+                attempts++
+                throw GpioException.ParseFailure("period", "abc")
+            }
+        }
+        assertEquals(1, attempts)
+    }
+}
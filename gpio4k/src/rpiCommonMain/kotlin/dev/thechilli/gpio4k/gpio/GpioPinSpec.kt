@@ -0,0 +1,62 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * A GPIO pin described by a string, so the backend can be picked in configuration instead of in code.
+ *
+ * Supported formats:
+ * - `sysfs:<pin>`, e.g. `sysfs:17`, for a [SysFsGpioPin].
+ * - `gpiod:<chip>:<pin>`, e.g. `gpiod:0:17`, for a [GpiodPin].
+ * - `mock:<name>`, e.g. `mock:door`, for a [MockedGpioPin].
+ */
+sealed class GpioPinSpec {
+    abstract fun open(): GpioPin
+
+    data class SysFs(val pinId: Int) : GpioPinSpec() {
+        override fun open() = SysFsGpioPin(pinId)
+    }
+
+    data class Gpiod(val chipId: Int, val pinId: Int) : GpioPinSpec() {
+        override fun open() = GpiodPin(chipId, pinId)
+    }
+
+    data class Mock(val name: String) : GpioPinSpec() {
+        override fun open() = MockedGpioPin(name)
+    }
+
+    companion object {
+        /**
+         * @throws IllegalArgumentException if the spec is malformed or names an unknown backend.
+         */
+        fun parse(spec: String): GpioPinSpec {
+            val parts = spec.trim().split(":")
+            val backend = parts.first()
+
+            fun number(text: String): Int = requireNotNull(text.toIntOrNull()?.takeIf { it >= 0 }) {
+                "Invalid number \"$text\" in GPIO pin spec \"$spec\""
+            }
+
+            return when (backend) {
+                "sysfs" -> {
+                    require(parts.size == 2) { "Expected sysfs:<pin>, got \"$spec\"" }
+                    SysFs(number(parts[1]))
+                }
+                "gpiod" -> {
+                    require(parts.size == 3) { "Expected gpiod:<chip>:<pin>, got \"$spec\"" }
+                    Gpiod(number(parts[1]), number(parts[2]))
+                }
+                "mock" -> {
+                    require(parts.size >= 2 && parts[1].isNotEmpty()) { "Expected mock:<name>, got \"$spec\"" }
+                    Mock(parts.drop(1).joinToString(":"))
+                }
+                else -> throw IllegalArgumentException("Unknown GPIO backend \"$backend\" in \"$spec\"")
+            }
+        }
+    }
+}
+
+/**
+ * Opens a GPIO pin from a string spec like `gpiod:0:17`. See [GpioPinSpec] for the supported formats.
+ *
+ * @throws IllegalArgumentException if the spec is malformed or names an unknown backend.
+ */
+fun openGpioPin(spec: String): GpioPin = GpioPinSpec.parse(spec).open()
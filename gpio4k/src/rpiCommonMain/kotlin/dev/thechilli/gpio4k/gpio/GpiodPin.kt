@@ -7,7 +7,11 @@ import dev.thechilli.gpio4k.utils.isDebug
  *
  * gpiod uses ioctl calls with some very elaborate structures to control GPIO pins.
  */
-class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
+class GpiodPin(
+    val gpioChipId: Int,
+    val pinId: Int,
+    private val system: SystemCommands = RealSystemCommands,
+) : GpioPin {
     override fun read(): Boolean {
         if(mode != GpioIOMode.INPUT)
             throw GpioException("Pin $pinId is not readable")
@@ -18,18 +22,17 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
         if(activeLow) args.add("-l")
         // Set bias
         args.add("-B")
-        args.add(when(bias) {
-            GpioLineBias.NONE -> "disable"
-            GpioLineBias.PULL_UP -> "pull-up"
-            GpioLineBias.PULL_DOWN -> "pull-down"
-        })
+        args.add(biasArgument)
         // Pass chip and pin id
         args.add(gpioChipId.toString())
         args.add(pinId.toString())
         // Read the pin
-        val (exitCode, output) = exec("gpiod", *args.toTypedArray())
+        val (exitCode, output) = system.exec("gpiod", *args.toTypedArray())
         if(exitCode != 0)
-            throw GpioException("Failed to read pin $pinId.\ngpioget exited with $exitCode.\n$output")
+            throw GpioException(
+                "Failed to read pin $pinId.\ngpioget exited with $exitCode.\n$output",
+                isRetryable = "busy" in output,
+            )
         return output.trim() == "1"
     }
 
@@ -45,19 +48,26 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
 
         // Kill the last set command if it's still running
         if(lastSetPid != 0L)
-            kill(lastSetPid)
+            system.kill(lastSetPid)
 
-        // gpioset [-l] -B <bias> -D <drive> -m signal <chip> <pin>=<value>
+        // Keep pin state until SIGTERM
+        lastSetPid = system.spawn("gpioset", *gpiosetArgs(value, "signal"))
+
+        lastState = value
+        forceSet = false
+    }
+
+    /**
+     * Arguments of a gpioset command setting the pin to [value], with the given `-m` mode.
+     */
+    private fun gpiosetArgs(value: Boolean, waitMode: String): Array<String> {
+        // gpioset [-l] -B <bias> -D <drive> -m <mode> <chip> <pin>=<value>
         val args = mutableListOf<String>()
         // Set pin to active low if necessary
         if(activeLow) args.add("-l")
         // Set bias
         args.add("-B")
-        args.add(when(bias) {
-            GpioLineBias.NONE -> "disable"
-            GpioLineBias.PULL_UP -> "pull-up"
-            GpioLineBias.PULL_DOWN -> "pull-down"
-        })
+        args.add(biasArgument)
         // Set drive mode
         args.add("-D")
         args.add(when(drive) {
@@ -65,29 +75,28 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
             GpioDriveMode.OPEN_DRAIN -> "open-drain"
             GpioDriveMode.OPEN_SOURCE -> "open-source"
         })
-        // Keep pin state until SIGTERM
         args.add("-m")
-        args.add("signal")
+        args.add(waitMode)
         // Pass chip and pin id
         args.add(gpioChipId.toString())
         args.add(pinId.toString() + "=" + if(value) "1" else "0")
-        // Write the pin
-        lastSetPid = spawn("gpioset", *args.toTypedArray())
-
-        lastState = value
-        forceSet = false
+        return args.toTypedArray()
     }
 
-    override var mode = GpioIOMode.INPUT
+    override var mode = GpioIOMode.DEFAULT
         private set
 
     override fun setMode(mode: GpioIOMode): GpioPin {
         if(this.mode == GpioIOMode.OUTPUT && mode == GpioIOMode.INPUT) {
             // Kill the last set command if it's still running
             if(lastSetPid != 0L) {
-                kill(lastSetPid)
+                system.kill(lastSetPid)
                 lastSetPid = 0
             }
+            // Request the line as an input once, so the configured bias applies while it floats,
+            // instead of only on the next read
+            if(bias != GpioLineBias.NONE)
+                system.exec("gpioget", "-B", biasArgument, gpioChipId.toString(), pinId.toString())
         }
         this.mode = mode
         forceSet = true
@@ -103,7 +112,7 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
         return this
     }
 
-    override var bias = GpioLineBias.NONE
+    override var bias = GpioLineBias.DEFAULT
         private set
 
     override fun setBias(bias: GpioLineBias): GpioPin {
@@ -112,7 +121,19 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
         return this
     }
 
-    override var drive = GpioDriveMode.PUSH_PULL
+    /**
+     * The bias is passed to every gpioget and gpioset call, so the stored one is the one applied to the line.
+     */
+    override fun readBias(): GpioLineBias = bias
+
+    private val biasArgument: String
+        get() = when(bias) {
+            GpioLineBias.NONE -> "disable"
+            GpioLineBias.PULL_UP -> "pull-up"
+            GpioLineBias.PULL_DOWN -> "pull-down"
+        }
+
+    override var drive = GpioDriveMode.DEFAULT
         private set
 
     override fun setDrive(drive: GpioDriveMode): GpioPin {
@@ -121,12 +142,48 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
         return this
     }
 
+    /**
+     * Whether the line is reset when the pin is closed.
+     *
+     * If enabled (the default), an output is first driven to its inactive level, waiting for gpioset to apply it,
+     * and then the line is reconfigured as an input. Otherwise, the line is released as is, and keeps its last level,
+     * e.g. leaving a relay energized.
+     *
+     * @see close
+     */
+    var resetOnClose = true
+
+    /**
+     * Releases the line, resetting it first if [resetOnClose] is set.
+     *
+     * @throws GpioException if resetting the line fails.
+     */
     override fun close() {
         if(lastSetPid != 0L) {
             // Kill the last set command if it's still running
-            kill(lastSetPid)
+            system.kill(lastSetPid)
             lastSetPid = 0
         }
+
+        if(!resetOnClose) return
+
+        if(mode == GpioIOMode.OUTPUT) {
+            // Drive the inactive level and wait for it to be applied, instead of racing a background gpioset
+            val (exitCode, output) = system.exec("gpioset", *gpiosetArgs(false, "exit"))
+            if(exitCode != 0)
+                throw GpioException(
+                    "Failed to reset pin $pinId.\ngpioset exited with $exitCode.\n$output",
+                    isRetryable = "busy" in output,
+                )
+        }
+
+        // Requesting the line as an input once switches it to input mode
+        val (exitCode, output) = system.exec("gpioget", gpioChipId.toString(), pinId.toString())
+        if(exitCode != 0)
+            throw GpioException(
+                "Failed to release pin $pinId as an input.\ngpioget exited with $exitCode.\n$output",
+                isRetryable = "busy" in output,
+            )
     }
 
     protected fun finalize() {
@@ -134,7 +191,7 @@ class GpiodPin(val gpioChipId: Int, val pinId: Int) : GpioPin {
             if(lastSetPid != 0L) {
                 // TODO Log this to error stream instead of stdout
                 println("[DEBUG] GpiodPin $pinId has not been closed properly before destruction. Closing now.")
-                kill(lastSetPid)
+                system.kill(lastSetPid)
             }
         }
     }
@@ -0,0 +1,29 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * An in-memory [SysFs], for testing the sysfs backends off a Raspberry Pi.
+ *
+ * Files are plain entries in [files], and a directory exists as long as any file lives under it.
+ * Unlike the kernel, writes never have side effects, so e.g. writing to `export` doesn't create the exported files.
+ */
+class MockedSysFs(
+    val files: MutableMap<String, String> = mutableMapOf(),
+) : SysFs {
+    /**
+     * Every write, in order, as path and value pairs.
+     */
+    val writes = mutableListOf<Pair<String, String>>()
+
+    override fun read(path: String): String {
+        return files[path]?.trim() ?: throw GpioException("No such file: $path")
+    }
+
+    override fun write(path: String, value: String) {
+        writes.add(path to value)
+        files[path] = value
+    }
+
+    override fun exists(path: String): Boolean {
+        return path in files || files.keys.any { it.startsWith("$path/") }
+    }
+}
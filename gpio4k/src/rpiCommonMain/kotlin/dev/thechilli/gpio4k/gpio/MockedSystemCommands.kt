@@ -0,0 +1,28 @@
+package dev.thechilli.gpio4k.gpio
+
+/**
+ * A [SystemCommands] recording the commands instead of running them, for testing the gpiod backend
+ * off a Raspberry Pi.
+ *
+ * Every command succeeds with no output, unless a result is set for it in [results].
+ */
+class MockedSystemCommands(
+    val results: MutableMap<String, Pair<Int, String>> = mutableMapOf(),
+) : SystemCommands {
+    /**
+     * Every command run or spawned, in order, with the command first.
+     */
+    val commands = mutableListOf<List<String>>()
+
+    private var nextPid = 1000L
+
+    override fun exec(command: String, vararg args: String): Pair<Int, String> {
+        commands.add(listOf(command, *args))
+        return results[command] ?: (0 to "")
+    }
+
+    override fun spawn(command: String, vararg args: String): Long {
+        commands.add(listOf(command, *args))
+        return nextPid++
+    }
+}
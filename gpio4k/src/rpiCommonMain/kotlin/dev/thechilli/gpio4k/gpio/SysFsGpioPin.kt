@@ -6,18 +6,21 @@ package dev.thechilli.gpio4k.gpio
  * The API may or may not be deprecated (yes, it is), but it's simply the easiest way to access GPIO.
  * This class may get deprecated in the future as well.
  *
- * @throws
+ * @param sysFs Where the sysfs files are accessed, replaceable for testing.
  */
-class SysFsGpioPin(val pinId: Int) : GpioPin {
+class SysFsGpioPin(
+    val pinId: Int,
+    private val sysFs: SysFs = RealSysFs,
+) : GpioPin {
     val pinPath = "/sys/class/gpio/gpio$pinId"
 
     init {
         // Reserve the pin
         val exportPath = "/sys/class/gpio/export"
         try {
-            writeSysFs(exportPath, pinId.toString())
+            sysFs.write(exportPath, pinId.toString())
         } catch (e: Exception) {
-            throw GpioException("Failed to reserve pin $pinId", e)
+            throw GpioException.Unavailable("Failed to reserve pin $pinId", e)
         }
 
         reset()
@@ -27,7 +30,7 @@ class SysFsGpioPin(val pinId: Int) : GpioPin {
         val valuePath = "$pinPath/value"
         if(mode != GpioIOMode.INPUT)
             throw GpioException("Pin $pinId is not readable")
-        val value = readSysFsString(valuePath)
+        val value = sysFs.read(valuePath)
         return value == "1"
     }
 
@@ -35,22 +38,22 @@ class SysFsGpioPin(val pinId: Int) : GpioPin {
         val valuePath = "$pinPath/value"
         if(mode != GpioIOMode.OUTPUT)
             throw GpioException("Pin $pinId is not writable")
-        writeSysFs(valuePath, if (value) "1" else "0")
+        sysFs.write(valuePath, if (value) "1" else "0")
     }
 
     override val mode: GpioIOMode
         get() {
             val directionPath = "$pinPath/direction"
-            return when (val direction = readSysFsString(directionPath)) {
+            return when (val direction = sysFs.read(directionPath)) {
                 "in" -> GpioIOMode.INPUT
                 "out" -> GpioIOMode.OUTPUT
-                else -> throw IllegalStateException("Invalid direction: $direction")
+                else -> throw GpioException.ParseFailure("direction", direction)
             }
         }
 
     override fun setMode(mode: GpioIOMode): SysFsGpioPin {
         val directionPath = "$pinPath/direction"
-        writeSysFs(directionPath, when (mode) {
+        sysFs.write(directionPath, when (mode) {
             GpioIOMode.INPUT -> "in"
             GpioIOMode.OUTPUT -> "out"
         })
@@ -60,13 +63,13 @@ class SysFsGpioPin(val pinId: Int) : GpioPin {
     override val activeLow: Boolean
         get() {
             val activeLowPath = "$pinPath/active_low"
-            val activeLow = readSysFsString(activeLowPath)
+            val activeLow = sysFs.read(activeLowPath)
             return activeLow == "1"
         }
 
     override fun setActiveLow(activeLow: Boolean): SysFsGpioPin {
         val activeLowPath = "$pinPath/active_low"
-        writeSysFs(activeLowPath, if (activeLow) "1" else "0")
+        sysFs.write(activeLowPath, if (activeLow) "1" else "0")
         return this
     }
 
@@ -75,7 +78,7 @@ class SysFsGpioPin(val pinId: Int) : GpioPin {
 
     override fun setBias(bias: GpioLineBias): SysFsGpioPin {
         if(bias != GpioLineBias.NONE)
-            throw UnsupportedOperationException("Bias is not supported by sysfs")
+            throw GpioException.Unsupported("Bias $bias", "sysfs (pin $pinId)")
         return this
     }
 
@@ -84,13 +87,13 @@ class SysFsGpioPin(val pinId: Int) : GpioPin {
 
     override fun setDrive(drive: GpioDriveMode): SysFsGpioPin {
         if(drive != GpioDriveMode.PUSH_PULL)
-            throw UnsupportedOperationException("Drive mode is not supported by sysfs")
+            throw GpioException.Unsupported("Drive mode $drive", "sysfs (pin $pinId)")
         return this
     }
 
     override fun close() {
         // Release the pin
         val unexportPath = "/sys/class/gpio/unexport"
-        writeSysFs(unexportPath, pinId.toString())
+        sysFs.write(unexportPath, pinId.toString())
     }
 }
@@ -13,3 +13,31 @@ expect fun spawn(command: String, vararg args: String): Long
 fun kill(pid: Long) {
     exec("kill", "-s", "SIGTERM", pid.toString())
 }
+
+/**
+ * Runs the command line tools, so the gpiod backend can be pointed at a [MockedSystemCommands] in tests.
+ */
+interface SystemCommands {
+    /**
+     * @see dev.thechilli.gpio4k.gpio.exec
+     */
+    fun exec(command: String, vararg args: String): Pair<Int, String>
+
+    /**
+     * @see dev.thechilli.gpio4k.gpio.spawn
+     */
+    fun spawn(command: String, vararg args: String): Long
+
+    fun kill(pid: Long) {
+        exec("kill", "-s", "SIGTERM", pid.toString())
+    }
+}
+
+/**
+ * Actually runs the commands, through [exec] and [spawn].
+ */
+object RealSystemCommands : SystemCommands {
+    override fun exec(command: String, vararg args: String) = dev.thechilli.gpio4k.gpio.exec(command, *args)
+
+    override fun spawn(command: String, vararg args: String) = dev.thechilli.gpio4k.gpio.spawn(command, *args)
+}
@@ -3,8 +3,50 @@ package dev.thechilli.gpio4k.gpio
 import dev.thechilli.gpio4k.utils.decodeToString
 import dev.thechilli.gpio4k.utils.encodeToUByteArray
 
+/**
+ * Writes [value] to a sysfs attribute file.
+ *
+ * @throws GpioException if the file can't be written, with the platform's I/O error as its cause where available.
+ * Failures caused by a busy device are marked as [retryable][GpioException.isRetryable].
+ */
 expect fun writeSysFs(path: String, value: UByteArray)
 fun writeSysFs(path: String, value: String) = writeSysFs(path, value.encodeToUByteArray())
 
+/**
+ * Reads the contents of a sysfs attribute file.
+ *
+ * @throws GpioException if the file can't be read, with the platform's I/O error as its cause where available.
+ * Failures caused by a busy device are marked as [retryable][GpioException.isRetryable].
+ */
 expect fun readSysFs(path: String): UByteArray
 fun readSysFsString(path: String) = readSysFs(path).decodeToString().trim()
+
+/**
+ * Checks whether a sysfs file or directory exists, e.g. whether a pin is already exported.
+ */
+expect fun existsSysFs(path: String): Boolean
+
+/**
+ * Access to the sysfs attribute files, so the sysfs backends can be pointed at a [MockedSysFs] in tests.
+ */
+interface SysFs {
+    /**
+     * Reads a file, with surrounding whitespace trimmed.
+     */
+    fun read(path: String): String
+
+    fun write(path: String, value: String)
+
+    fun exists(path: String): Boolean
+}
+
+/**
+ * The actual sysfs, through [readSysFs], [writeSysFs] and [existsSysFs].
+ */
+object RealSysFs : SysFs {
+    override fun read(path: String) = readSysFsString(path)
+
+    override fun write(path: String, value: String) = writeSysFs(path, value)
+
+    override fun exists(path: String) = existsSysFs(path)
+}
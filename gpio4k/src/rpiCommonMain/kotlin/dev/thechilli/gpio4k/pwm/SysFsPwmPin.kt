@@ -1,28 +1,37 @@
 package dev.thechilli.gpio4k.pwm
 
 import dev.thechilli.gpio4k.gpio.GpioException
-import dev.thechilli.gpio4k.gpio.readSysFsString
-import dev.thechilli.gpio4k.gpio.writeSysFs
+import dev.thechilli.gpio4k.gpio.RealSysFs
+import dev.thechilli.gpio4k.gpio.SysFs
 
 /**
  * A PWM pin using the sysfs interface.
  *
  * Unlike its GPIO counterpart, this API is not deprecated.
  *
+ * The channel is exported when the pin is created, reusing it if it's already exported, and unexported on [close].
+ *
  * - [Documentation](https://www.kernel.org/doc/Documentation/pwm.txt)
+ *
+ * @param sysFs Where the sysfs files are accessed, replaceable for testing.
  */
-class SysFsPwmPin(val chipId: Int, val channelId: Int) : PwmPin {
+class SysFsPwmPin(
+    val chipId: Int,
+    val channelId: Int,
+    private val sysFs: SysFs = RealSysFs,
+) : PwmPin {
     constructor(channelId: Int): this(0, channelId)
 
     val pwmPath = "/sys/class/pwm/pwmchip$chipId/pwm$channelId"
 
     init {
-        // Reserve the channel
+        // Reserve the channel, unless it's still exported, e.g. after a previous run crashed before closing it
         val exportPath = "/sys/class/pwm/pwmchip$chipId/export"
         try {
-            writeSysFs(exportPath, channelId.toString())
+            if (!sysFs.exists(pwmPath))
+                sysFs.write(exportPath, channelId.toString())
         } catch (e: Exception) {
-            throw GpioException("Failed to reserve channel $channelId", e)
+            throw GpioException.Unavailable("Failed to reserve channel $channelId", e)
         }
 
         reset()
@@ -32,55 +41,57 @@ class SysFsPwmPin(val chipId: Int, val channelId: Int) : PwmPin {
         private set
 
     override fun enable() {
-        writeSysFs("$pwmPath/enable", "1")
+        sysFs.write("$pwmPath/enable", "1")
         enabled = true
     }
 
     override fun disable() {
-        writeSysFs("$pwmPath/enable", "0")
+        sysFs.write("$pwmPath/enable", "0")
         enabled = false
     }
 
     override val periodNs: Long
         get() {
             val periodPath = "$pwmPath/period"
-            return readSysFsString(periodPath).toLong()
+            val period = sysFs.read(periodPath)
+            return period.toLongOrNull() ?: throw GpioException.ParseFailure("period", period)
         }
 
     override fun setPeriodNs(periodNs: Long): SysFsPwmPin {
-        writeSysFs("$pwmPath/period", periodNs.toString())
+        sysFs.write("$pwmPath/period", periodNs.toString())
         return this
     }
 
     override val dutyCycleNs: Long
         get() {
             val dutyCyclePath = "$pwmPath/duty_cycle"
-            return readSysFsString(dutyCyclePath).toLong()
+            val dutyCycle = sysFs.read(dutyCyclePath)
+            return dutyCycle.toLongOrNull() ?: throw GpioException.ParseFailure("duty cycle", dutyCycle)
         }
 
     override fun setDutyCycleNs(dutyCycleNs: Long): SysFsPwmPin {
-        writeSysFs("$pwmPath/duty_cycle", dutyCycleNs.toString())
+        sysFs.write("$pwmPath/duty_cycle", dutyCycleNs.toString())
         return this
     }
 
     override val activeLow: Boolean
         get() {
             val polarityPath = "$pwmPath/polarity"
-            return when(readSysFsString(polarityPath)) {
+            return when(val polarity = sysFs.read(polarityPath)) {
                 "normal" -> false
                 "inversed" -> true
-                else -> throw IllegalStateException("Invalid polarity")
+                else -> throw GpioException.ParseFailure("polarity", polarity)
             }
         }
 
     override fun setActiveLow(activeLow: Boolean): SysFsPwmPin {
-        writeSysFs("$pwmPath/polarity", if(activeLow) "inversed" else "normal")
+        sysFs.write("$pwmPath/polarity", if(activeLow) "inversed" else "normal")
         return this
     }
 
     override fun close() {
         // Unexport the channel
         val unexportPath = "/sys/class/pwm/pwmchip$chipId/unexport"
-        writeSysFs(unexportPath, channelId.toString())
+        sysFs.write(unexportPath, channelId.toString())
     }
 }
@@ -0,0 +1,123 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.concurrent.thread
+
+/**
+ * An output that is only kept active while the main loop keeps calling [feed], e.g. a door latch solenoid that
+ * must be released even if the application hangs.
+ *
+ * A background daemon thread checks the output every [timeoutMs]` / 4` and releases it if it hasn't been fed for
+ * [timeoutMs] milliseconds. If writing the pin fails, the thread keeps running and retries on its next check,
+ * and [activate] and [feed] throw until a release succeeds. The thread is stopped on [close],
+ * which also releases the output.
+ *
+ * The pin is written logically, so active-low relays work as long as [GpioPin.activeLow] is set on the [pin].
+ * All pin access goes through this object, so the pin must not be written from elsewhere while it's in use.
+ *
+ * @param nanoTime Source of the monotonic time in nanoseconds, replaceable in tests.
+ */
+class WatchdogOutput(
+    val pin: GpioPin,
+    val timeoutMs: Long,
+    private val nanoTime: () -> Long = System::nanoTime,
+) : AutoCloseable {
+    init {
+        require(timeoutMs > 0) { "Timeout must be positive" }
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(false)
+    }
+
+    private val lock = Any()
+
+    @Volatile
+    private var running = true
+
+    private var lastFedNs = 0L
+
+    @Volatile
+    var isActive: Boolean = false
+        private set
+
+    /**
+     * Whether the watchdog released the output because it wasn't fed in time. Cleared on [activate].
+     */
+    @Volatile
+    var hasTripped: Boolean = false
+        private set
+
+    /**
+     * The exception thrown by the last failed release of the tripped watchdog, or `null` if none failed since.
+     */
+    @Volatile
+    var releaseFailure: Exception? = null
+        private set
+
+    private val watchdog = thread(isDaemon = true, name = "WatchdogOutput") {
+        while (running) {
+            synchronized(lock) {
+                if (isActive && nanoTime() - lastFedNs > timeoutMs * 1_000_000) {
+                    hasTripped = true
+                    try {
+                        releaseLocked()
+                    } catch (e: Exception) {
+                        // Still active, so the release is retried on the next check
+                        releaseFailure = e
+                    }
+                }
+            }
+            try {
+                Thread.sleep((timeoutMs / 4).coerceAtLeast(1))
+            } catch (e: InterruptedException) {
+                break
+            }
+        }
+    }
+
+    /**
+     * Activates the output. It has to be fed every [timeoutMs] milliseconds from now on to stay active.
+     *
+     * @throws GpioException if the watchdog failed to release the output and hasn't managed to since.
+     */
+    fun activate() = synchronized(lock) {
+        checkReleased()
+        hasTripped = false
+        lastFedNs = nanoTime()
+        isActive = true
+        pin.write(true)
+    }
+
+    /**
+     * Resets the timeout, keeping the output active.
+     *
+     * @throws GpioException if the watchdog failed to release the output and hasn't managed to since.
+     */
+    fun feed() = synchronized(lock) {
+        checkReleased()
+        lastFedNs = nanoTime()
+    }
+
+    /**
+     * Releases the output immediately.
+     */
+    fun release() = synchronized(lock) {
+        releaseLocked()
+    }
+
+    private fun checkReleased() {
+        val failure = releaseFailure ?: return
+        throw GpioException("Watchdog failed to release the output", failure)
+    }
+
+    private fun releaseLocked() {
+        pin.write(false)
+        isActive = false
+        releaseFailure = null
+    }
+
+    override fun close() {
+        running = false
+        watchdog.interrupt()
+        watchdog.join()
+        release()
+    }
+}
@@ -1,11 +1,24 @@
 package dev.thechilli.gpio4k.gpio
 
 import java.io.File
+import java.io.IOException
 
 actual fun writeSysFs(path: String, value: UByteArray) {
-    File(path).writeBytes(value.toByteArray())
+    try {
+        File(path).writeBytes(value.toByteArray())
+    } catch (e: IOException) {
+        throw GpioException("Failed to write to $path", e, isRetryable = isBusy(e))
+    }
 }
 
 actual fun readSysFs(path: String): UByteArray {
-    return File(path).readBytes().toUByteArray()
+    try {
+        return File(path).readBytes().toUByteArray()
+    } catch (e: IOException) {
+        throw GpioException("Failed to read from $path", e, isRetryable = isBusy(e))
+    }
 }
+
+actual fun existsSysFs(path: String): Boolean = File(path).exists()
+
+private fun isBusy(e: IOException) = e.message?.contains("busy", ignoreCase = true) == true
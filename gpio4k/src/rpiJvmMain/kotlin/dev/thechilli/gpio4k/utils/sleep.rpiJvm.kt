@@ -5,5 +5,5 @@ actual fun sleepMs(millis: Int) {
 }
 
 actual fun sleepUs(micros: Int) {
-    Thread.sleep(0, micros)
+    Thread.sleep(micros / 1000L, micros % 1000 * 1000)
 }
@@ -0,0 +1,30 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertIs
+
+class GpioPinSpecTest {
+    @Test
+    fun `Specs should parse for every backend`() {
+        assertEquals(GpioPinSpec.SysFs(17), GpioPinSpec.parse("sysfs:17"))
+        assertEquals(GpioPinSpec.Gpiod(0, 27), GpioPinSpec.parse("gpiod:0:27"))
+        assertEquals(GpioPinSpec.Mock("door:latch"), GpioPinSpec.parse("mock:door:latch"))
+    }
+
+    @Test
+    fun `Mock spec should open a mocked pin`() {
+        val pin = openGpioPin("mock:buzzer")
+
+        assertIs<MockedGpioPin>(pin)
+        assertEquals("buzzer", pin.name)
+    }
+
+    @Test
+    fun `Invalid specs should be rejected`() {
+        for (spec in listOf("raw:17", "sysfs", "sysfs:x", "gpiod:17", "gpiod:0:-1", "mock:")) {
+            assertFailsWith<IllegalArgumentException>(spec) { GpioPinSpec.parse(spec) }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class GpiodPinTest {
+    @Test
+    fun `Closing an output should drive it inactive before releasing it`() {
+        val system = MockedSystemCommands()
+        val pin = GpiodPin(0, 17, system)
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(true)
+
+        pin.close()
+
+        assertEquals(
+            listOf(
+                listOf("gpioset", "-B", "disable", "-D", "push-pull", "-m", "signal", "0", "17=1"),
+                listOf("kill", "-s", "SIGTERM", "1000"),
+                listOf("gpioset", "-B", "disable", "-D", "push-pull", "-m", "exit", "0", "17=0"),
+                listOf("gpioget", "0", "17"),
+            ),
+            system.commands,
+        )
+    }
+
+    @Test
+    fun `Closing without reset should only stop gpioset`() {
+        val system = MockedSystemCommands()
+        val pin = GpiodPin(0, 17, system)
+        pin.resetOnClose = false
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(true)
+
+        pin.close()
+
+        assertEquals(listOf("kill", "-s", "SIGTERM", "1000"), system.commands.last())
+        assertEquals(2, system.commands.size)
+    }
+
+    @Test
+    fun `Failing to reset the line should be reported`() {
+        val system = MockedSystemCommands()
+        val pin = GpiodPin(0, 17, system)
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(true)
+
+        system.results["gpioset"] = 1 to "gpioset: error setting the GPIO line values: Device or resource busy"
+        val exception = assertFailsWith<GpioException> { pin.close() }
+        assertEquals(true, exception.isRetryable)
+
+        system.results.remove("gpioset")
+        system.results["gpioget"] = 1 to "gpioget: error reading GPIO values"
+        assertFailsWith<GpioException> { pin.close() }
+    }
+}
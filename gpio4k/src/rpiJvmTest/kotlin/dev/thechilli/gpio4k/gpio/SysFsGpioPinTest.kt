@@ -0,0 +1,44 @@
+package dev.thechilli.gpio4k.gpio
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertTrue
+
+class SysFsGpioPinTest {
+    private val pinPath = "/sys/class/gpio/gpio17"
+
+    @Test
+    fun `Pin should read and write its value file`() {
+        val sysFs = MockedSysFs()
+        val pin = SysFsGpioPin(17, sysFs)
+
+        pin.setMode(GpioIOMode.OUTPUT)
+        pin.write(true)
+        assertEquals("1", sysFs.files["$pinPath/value"])
+
+        pin.setMode(GpioIOMode.INPUT)
+        sysFs.files["$pinPath/value"] = "1\n"
+        assertTrue(pin.read())
+    }
+
+    @Test
+    fun `Invalid direction should be reported as a parse failure`() {
+        val sysFs = MockedSysFs()
+        val pin = SysFsGpioPin(17, sysFs)
+
+        sysFs.files["$pinPath/direction"] = "sideways"
+
+        val exception = assertFailsWith<GpioException.ParseFailure> { pin.mode }
+        assertEquals("sideways", exception.value)
+    }
+
+    @Test
+    fun `Unsupported settings should name the feature and backend`() {
+        val pin = SysFsGpioPin(17, MockedSysFs())
+
+        val exception = assertFailsWith<GpioException.Unsupported> { pin.setBias(GpioLineBias.PULL_UP) }
+
+        assertEquals("Bias PULL_UP is not supported by sysfs (pin 17)", exception.message)
+    }
+}
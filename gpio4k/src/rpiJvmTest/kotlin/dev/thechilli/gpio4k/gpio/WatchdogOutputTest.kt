@@ -0,0 +1,121 @@
+package dev.thechilli.gpio4k.gpio
+
+import java.util.concurrent.atomic.AtomicLong
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class WatchdogOutputTest {
+    /**
+     * A clock only moving when the test advances it, so the checks don't depend on the scheduling of the runner.
+     */
+    private class FakeClock {
+        private val nowNs = AtomicLong(0)
+
+        fun nanoTime(): Long = nowNs.get()
+
+        fun advanceMs(ms: Long) {
+            nowNs.addAndGet(ms * 1_000_000)
+        }
+    }
+
+    /**
+     * A pin failing the next [failures] writes of the inactive level, like a briefly busy line.
+     */
+    private class FlakyPin(val pin: MockedGpioPin) : GpioPin by pin {
+        @Volatile
+        var failures = 0
+
+        override fun write(value: Boolean) {
+            if (!value && failures > 0) {
+                failures--
+                throw GpioException.Unavailable("Line busy", isRetryable = true)
+            }
+            pin.write(value)
+        }
+    }
+
+    /**
+     * Waits for the watchdog thread to reach the [condition], failing after a generous deadline.
+     */
+    private fun awaitCondition(condition: () -> Boolean) {
+        val deadline = System.nanoTime() + 5_000_000_000
+        while (!condition()) {
+            check(System.nanoTime() < deadline) { "Condition not reached in time" }
+            Thread.sleep(1)
+        }
+    }
+
+    @Test
+    fun `Output should stay active while fed`() {
+        val clock = FakeClock()
+        val pin = MockedGpioPin("solenoid")
+        WatchdogOutput(pin, timeoutMs = 20, clock::nanoTime).use { output ->
+            output.activate()
+            repeat(5) {
+                clock.advanceMs(15)
+                output.feed()
+                // Several checks of the watchdog thread
+                Thread.sleep(20)
+            }
+
+            assertTrue(output.isActive)
+            assertEquals(true, pin.internallyExpected)
+        }
+        assertEquals(false, pin.internallyExpected)
+    }
+
+    @Test
+    fun `Output should be released after a missed feed`() {
+        val clock = FakeClock()
+        val pin = MockedGpioPin("solenoid")
+        WatchdogOutput(pin, timeoutMs = 20, clock::nanoTime).use { output ->
+            output.activate()
+
+            clock.advanceMs(21)
+            awaitCondition { !output.isActive }
+
+            assertTrue(output.hasTripped)
+            assertEquals(false, pin.internallyExpected)
+        }
+    }
+
+    @Test
+    fun `A failed release should be retried and reported`() {
+        val clock = FakeClock()
+        val pin = FlakyPin(MockedGpioPin("solenoid"))
+        WatchdogOutput(pin, timeoutMs = 20, clock::nanoTime).use { output ->
+            output.activate()
+            pin.failures = 1
+
+            clock.advanceMs(21)
+            awaitCondition { pin.failures == 0 }
+            awaitCondition { !output.isActive }
+
+            assertEquals(false, pin.internallyExpected)
+            output.feed()
+        }
+    }
+
+    @Test
+    fun `Feeding should throw while the release keeps failing`() {
+        val clock = FakeClock()
+        val pin = FlakyPin(MockedGpioPin("solenoid"))
+        val output = WatchdogOutput(pin, timeoutMs = 20, clock::nanoTime)
+        output.activate()
+        pin.failures = Int.MAX_VALUE
+
+        clock.advanceMs(21)
+        awaitCondition { output.releaseFailure != null }
+
+        assertTrue(output.isActive)
+        assertFailsWith<GpioException> { output.feed() }
+        assertFailsWith<GpioException> { output.activate() }
+
+        pin.failures = 0
+        output.close()
+        assertFalse(output.isActive)
+    }
+}
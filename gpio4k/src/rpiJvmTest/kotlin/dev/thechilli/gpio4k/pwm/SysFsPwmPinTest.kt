@@ -0,0 +1,59 @@
+package dev.thechilli.gpio4k.pwm
+
+import dev.thechilli.gpio4k.gpio.MockedSysFs
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class SysFsPwmPinTest {
+    private val chipPath = "/sys/class/pwm/pwmchip0"
+
+    @Test
+    fun `Creating a pin should export the channel and closing should unexport it`() {
+        val sysFs = MockedSysFs()
+
+        SysFsPwmPin(0, 1, sysFs).close()
+
+        assertEquals("$chipPath/export" to "1", sysFs.writes.first())
+        assertEquals("$chipPath/unexport" to "1", sysFs.writes.last())
+    }
+
+    @Test
+    fun `An already exported channel should be reused`() {
+        val sysFs = MockedSysFs(mutableMapOf("$chipPath/pwm0/enable" to "1"))
+
+        SysFsPwmPin(0, 0, sysFs)
+
+        assertFalse(sysFs.writes.any { it.first == "$chipPath/export" })
+    }
+
+    @Test
+    fun `Period, duty cycle and enable should go through their files`() {
+        val sysFs = MockedSysFs()
+        val pin = SysFsPwmPin(0, 0, sysFs)
+
+        pin.setPeriodNs(2_000_000)
+        pin.setRatio(0.25)
+        pin.enable()
+
+        assertEquals("2000000", sysFs.files["$chipPath/pwm0/period"])
+        assertEquals(500_000, pin.dutyCycleNs)
+        assertEquals("1", sysFs.files["$chipPath/pwm0/enable"])
+        assertTrue(pin.enabled)
+    }
+
+    @Test
+    fun `Disabling and enabling should keep the period and duty cycle`() {
+        val pin = SysFsPwmPin(0, 0, MockedSysFs())
+        pin.setPeriodNs(2_272_727)
+        pin.setDutyCycleNs(1_136_363)
+        pin.enable()
+
+        pin.disable()
+        pin.enable()
+
+        assertEquals(2_272_727, pin.periodNs)
+        assertEquals(1_136_363, pin.dutyCycleNs)
+    }
+}
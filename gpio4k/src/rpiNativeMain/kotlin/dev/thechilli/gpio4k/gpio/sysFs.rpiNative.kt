@@ -3,20 +3,46 @@ package dev.thechilli.gpio4k.gpio
 import kotlinx.cinterop.memScoped
 import kotlinx.cinterop.refTo
 import kotlinx.cinterop.toCValues
+import kotlinx.cinterop.toKString
 import platform.posix.*
 
 actual fun writeSysFs(path: String, value: UByteArray) { memScoped {
     val fd = open(path, O_WRONLY)
+    if (fd < 0) throw sysFsException("Failed to open $path for writing")
     val cVal = value.toCValues()
-    write(fd, cVal, value.size.toULong())
+    val bytesWritten = write(fd, cVal, value.size.toULong())
+    if (bytesWritten < 0) {
+        val exception = sysFsException("Failed to write to $path")
+        close(fd)
+        throw exception
+    }
     close(fd)
 } }
 
 actual fun readSysFs(path: String): UByteArray {
     val fd = open(path, O_RDONLY)
+    if (fd < 0) throw sysFsException("Failed to open $path for reading")
     // TODO Support more bytes than buffer size
     val buffer = UByteArray(1024)
     val bytesRead = read(fd, buffer.refTo(0), buffer.size.toULong())
+    if (bytesRead < 0) {
+        val exception = sysFsException("Failed to read from $path")
+        close(fd)
+        throw exception
+    }
     close(fd)
     return buffer.copyOf(bytesRead.toInt())
 }
+
+actual fun existsSysFs(path: String): Boolean = access(path, F_OK) == 0
+
+/**
+ * Builds an exception from the current `errno`, so it has to be called before any other libc call.
+ */
+private fun sysFsException(message: String): GpioException {
+    val error = errno
+    return GpioException(
+        "$message: ${strerror(error)?.toKString()}",
+        isRetryable = error == EBUSY || error == EAGAIN,
+    )
+}
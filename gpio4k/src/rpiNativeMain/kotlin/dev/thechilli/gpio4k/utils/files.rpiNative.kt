@@ -0,0 +1,44 @@
+package dev.thechilli.gpio4k.utils
+
+import kotlinx.cinterop.convert
+import kotlinx.cinterop.refTo
+import kotlinx.cinterop.toKString
+import platform.posix.errno
+import platform.posix.fclose
+import platform.posix.fopen
+import platform.posix.fread
+import platform.posix.fwrite
+import platform.posix.strerror
+
+actual fun readTextFile(path: String): String {
+    val file = fopen(path, "rb") ?: throw fileException("Failed to open $path for reading")
+    try {
+        var content = ByteArray(0)
+        val buffer = ByteArray(4096)
+        while (true) {
+            val bytesRead = fread(buffer.refTo(0), 1.convert(), buffer.size.convert(), file).toInt()
+            if (bytesRead <= 0) break
+            content += buffer.copyOf(bytesRead)
+        }
+        return content.decodeToString()
+    } finally {
+        fclose(file)
+    }
+}
+
+actual fun writeTextFile(path: String, text: String) {
+    val file = fopen(path, "wb") ?: throw fileException("Failed to open $path for writing")
+    try {
+        val bytes = text.encodeToByteArray()
+        if (bytes.isEmpty()) return
+        val bytesWritten = fwrite(bytes.refTo(0), 1.convert(), bytes.size.convert(), file).toInt()
+        if (bytesWritten != bytes.size) throw fileException("Failed to write to $path")
+    } finally {
+        fclose(file)
+    }
+}
+
+/**
+ * Builds an exception from the current `errno`, so it has to be called before any other libc call.
+ */
+private fun fileException(message: String) = IllegalStateException("$message: ${strerror(errno)?.toKString()}")
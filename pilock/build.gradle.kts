@@ -77,6 +77,13 @@ kotlin {
             dependsOn(desktopCommonMain)
         }
 
+        val desktopJvmTest by getting {
+            dependencies {
+                implementation(kotlin("test"))
+                implementation(kotlin("test-junit"))
+            }
+        }
+
         val desktopNativeMain by getting {
             dependsOn(desktopCommonMain)
         }
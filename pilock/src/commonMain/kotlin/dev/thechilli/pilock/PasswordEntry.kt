@@ -0,0 +1,62 @@
+package dev.thechilli.pilock
+
+/**
+ * Keeps track of a password being entered character by character, independently of how it's displayed.
+ *
+ * @param maxLength Maximum number of characters.
+ * @param allowedChars Characters that can be entered, in the order [cycleLast] steps through them.
+ */
+class PasswordEntry(
+    val maxLength: Int,
+    val allowedChars: List<Char> = "0123456789".toList(),
+) {
+    init {
+        require(maxLength > 0) { "Maximum length must be positive" }
+        require(allowedChars.isNotEmpty()) { "There must be at least one allowed character" }
+    }
+
+    var input: String = ""
+        private set
+
+    val isComplete: Boolean
+        get() = input.length == maxLength
+
+    /**
+     * Appends a character.
+     *
+     * @return `false` if the character is not allowed or the input is already full.
+     */
+    fun append(char: Char): Boolean {
+        if (char !in allowedChars || isComplete) return false
+        input += char
+        return true
+    }
+
+    /**
+     * Removes the last character.
+     *
+     * @return `false` if the input was already empty.
+     */
+    fun backspace(): Boolean {
+        if (input.isEmpty()) return false
+        input = input.dropLast(1)
+        return true
+    }
+
+    /**
+     * Replaces the last character by the one [steps] positions further in [allowedChars], wrapping around.
+     * Negative steps go backwards. Useful for entering characters with a rotary encoder.
+     *
+     * @return `false` if the input is empty.
+     */
+    fun cycleLast(steps: Int): Boolean {
+        if (input.isEmpty()) return false
+        val index = allowedChars.indexOf(input.last())
+        input = input.dropLast(1) + allowedChars[(index + steps).mod(allowedChars.size)]
+        return true
+    }
+
+    fun clear() {
+        input = ""
+    }
+}
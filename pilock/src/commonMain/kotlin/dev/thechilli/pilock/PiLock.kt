@@ -32,7 +32,10 @@ class PiLockApp(
         lcd.clearDisplay()
     }
 
-    var currentInput = ""
+    val code = "13245768"
+    val codeLength get() = code.length
+
+    val passwordEntry = PasswordEntry(codeLength)
 
     fun update() {
         onBeforeUpdate.invoke(Unit)
@@ -41,36 +44,34 @@ class PiLockApp(
 
         if(input.isNotEmpty()) {
             // Process input
-            if(input[0] in codeChars) {
-                if(currentInput.length < codeLength) {
-                    currentInput += input[0]
+            if(input[0] in passwordEntry.allowedChars) {
+                if(passwordEntry.append(input[0])) {
                     buzz(BuzzerReason.OK)
                 } else {
                     buzz(BuzzerReason.FAIL)
                 }
             } else if(input[0] == '*') {
-                if(currentInput.isNotEmpty()) {
-                    currentInput = currentInput.dropLast(1)
+                if(passwordEntry.backspace()) {
                     buzz(BuzzerReason.CANCEL)
                 } else {
                     buzz(BuzzerReason.FAIL)
                 }
             } else if(input[0] == '#') {
-                if(currentInput == code) {
+                if(passwordEntry.input == code) {
                     drawUnlockScreen()
                     buzz(BuzzerReason.UNLOCKED)
                     onAfterUpdate.invoke(Unit)
                     sleepMs(3000)
-                    currentInput = ""
+                    passwordEntry.clear()
                     return
                 } else {
                     buzz(BuzzerReason.WRONG_CODE)
-                    currentInput = ""
+                    passwordEntry.clear()
                 }
             }
         }
 
-        drawMainScreen(currentInput)
+        drawMainScreen(passwordEntry.input)
 
         onAfterUpdate.invoke(Unit)
 
@@ -91,10 +92,6 @@ class PiLockApp(
         )
     }
 
-    val codeChars = "0123456789".toSet()
-    val code = "13245768"
-    val codeLength get() = code.length
-
     enum class BuzzerReason {
         OK,
         CANCEL,
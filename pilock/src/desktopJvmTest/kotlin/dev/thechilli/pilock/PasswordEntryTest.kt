@@ -0,0 +1,52 @@
+package dev.thechilli.pilock
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFalse
+import kotlin.test.assertTrue
+
+class PasswordEntryTest {
+    @Test
+    fun `Entry should reject characters past the maximum length`() {
+        val entry = PasswordEntry(4)
+
+        "1234".forEach { assertTrue(entry.append(it)) }
+
+        assertTrue(entry.isComplete)
+        assertFalse(entry.append('5'))
+        assertEquals("1234", entry.input)
+    }
+
+    @Test
+    fun `Entry should reject characters that are not allowed`() {
+        val entry = PasswordEntry(4)
+
+        assertFalse(entry.append('#'))
+        assertEquals("", entry.input)
+    }
+
+    @Test
+    fun `Backspace should fail on empty input`() {
+        val entry = PasswordEntry(4)
+        entry.append('1')
+
+        assertTrue(entry.backspace())
+        assertFalse(entry.backspace())
+    }
+
+    @Test
+    fun `Cycling the last digit should wrap around`() {
+        val entry = PasswordEntry(4)
+        entry.append('1')
+        entry.append('9')
+
+        entry.cycleLast(1)
+        assertEquals("10", entry.input)
+
+        entry.cycleLast(-1)
+        assertEquals("19", entry.input)
+
+        entry.cycleLast(-12)
+        assertEquals("17", entry.input)
+    }
+}
@@ -6,15 +6,15 @@ import dev.thechilli.gpio4k.utils.closingScope
 import dev.thechilli.gpio4k.utils.sleepMs
 
 fun main() = closingScope {
-    // LCD pins
-    val resetPin = GpiodPin(0, 15).autoClose().setActiveLow(true)
-    val rsPin = GpiodPin(0, 0).autoClose()
-    val enPin = GpiodPin(0, 5).autoClose()
+    // LCD pins, owned and closed by the display
+    val resetPin = GpiodPin(0, 15).setActiveLow(true)
+    val rsPin = GpiodPin(0, 0)
+    val enPin = GpiodPin(0, 5)
 
     // Consecutive pins for data
     val dataPins = listOf(17, 27, 22, 24, 10, 9, 11, 7).map {
         GpiodPin(0, it)
-    }.autoCloseAll().asReversed()
+    }.asReversed()
 
     val lcd = DirectDOGM204Display(
         resetPin,
@@ -24,8 +24,9 @@ fun main() = closingScope {
         enPin,
         dataPins,
         4,
-        20
-    )
+        20,
+        ownsPins = true,
+    ).autoClose()
 
     println("Initializing display…")
 